@@ -0,0 +1,31 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use std::io::Write;
+use uucore::checksum::{perform_checksum_validation, ChecksumOptions, ChecksumVerbose};
+
+fuzz_target!(|data: &[u8]| {
+    // The validator reads checksum files by name, so stage the fuzzer
+    // input as one.
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    file.write_all(data).unwrap();
+    let path = file.path().as_os_str();
+
+    // Exercise both the permissive and the strict code paths; only
+    // panics matter here, the parser is expected to reject most inputs.
+    for (strict, ignore_missing) in [(false, false), (true, true)] {
+        let opts = ChecksumOptions {
+            binary: false,
+            ignore_missing,
+            warn_missing: false,
+            strict,
+            verbose: ChecksumVerbose::Status,
+        };
+        _ = perform_checksum_validation(std::iter::once(path), None, None, opts);
+    }
+});
@@ -0,0 +1,35 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use uu_seq::number::PreciseNumber;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(x) = s.parse::<PreciseNumber>() else {
+        return;
+    };
+
+    // Formatting a value like 1e999999999 would expand every digit;
+    // don't let the round-trip check turn into an OOM report.
+    if x.num_integral_digits + x.num_fractional_digits > 1_000_000 {
+        return;
+    }
+
+    // A successfully parsed number must round-trip through formatting,
+    // except NaN, which never compares equal to itself.
+    let formatted = x.number.to_string();
+    let y = formatted
+        .parse::<PreciseNumber>()
+        .unwrap_or_else(|_| panic!("reparse failed: {s:?} -> {formatted:?}"));
+    assert!(
+        formatted == "nan" || x.number == y.number,
+        "value changed in round-trip: {s:?} -> {formatted:?}"
+    );
+});
@@ -7,7 +7,7 @@
 
 use clap::{crate_version, Arg, ArgAction, Command};
 use std::fs::File;
-use std::io::{self, ErrorKind, Read, Seek, SeekFrom};
+use std::io::{self, ErrorKind, Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
 use uucore::display::Quotable;
 use uucore::encoding::{
@@ -190,23 +190,30 @@ pub fn handle_input<R: Read + Seek>(input: &mut R, format: Format, config: Confi
 
     let supports_fast_decode_and_encode_ref = supports_fast_decode_and_encode.as_ref();
 
-    let mut stdout_lock = io::stdout().lock();
+    // Stdout is line buffered, and wrapped encoded output contains a newline
+    // every `wrap_cols` characters, which would mean a write syscall per
+    // line. Batch the writes with an explicit buffer instead.
+    const OUTPUT_BUFFER_SIZE: usize = 64 * 1_024;
+
+    let mut output = io::BufWriter::with_capacity(OUTPUT_BUFFER_SIZE, io::stdout().lock());
 
     if config.decode {
         fast_decode::fast_decode(
             input,
-            &mut stdout_lock,
+            &mut output,
             supports_fast_decode_and_encode_ref,
             config.ignore_garbage,
-        )
+        )?;
     } else {
         fast_encode::fast_encode(
             input,
-            &mut stdout_lock,
+            &mut output,
             supports_fast_decode_and_encode_ref,
             config.wrap_cols,
-        )
+        )?;
     }
+
+    Ok(output.flush()?)
 }
 
 pub fn get_supports_fast_decode_and_encode(
@@ -10,7 +10,7 @@ use std::ffi::OsString;
 use std::fs;
 use std::os::unix::fs::{MetadataExt, PermissionsExt};
 use std::path::Path;
-use uucore::display::Quotable;
+use uucore::display::{diag_quoted, Quotable};
 use uucore::error::{set_exit_code, ExitCode, UResult, USimpleError, UUsageError};
 use uucore::fs::display_permissions_unix;
 use uucore::libc::mode_t;
@@ -106,7 +106,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
             Err(err) => {
                 return Err(USimpleError::new(
                     1,
-                    format!("cannot stat attributes of {}: {}", fref.quote(), err),
+                    format!("cannot stat attributes of {}: {}", diag_quoted(fref), err),
                 ))
             }
         },
@@ -262,7 +262,7 @@ impl Chmoder {
                     if !self.quiet {
                         show!(USimpleError::new(
                             1,
-                            format!("cannot operate on dangling symlink {}", filename.quote()),
+                            format!("cannot operate on dangling symlink {}", diag_quoted(filename)),
                         ));
                         set_exit_code(1);
                     }
@@ -278,7 +278,7 @@ impl Chmoder {
                         1,
                         format!(
                             "cannot access {}: No such file or directory",
-                            filename.quote()
+                            diag_quoted(filename)
                         )
                     ));
                 }
@@ -297,7 +297,7 @@ impl Chmoder {
                     1,
                     format!(
                         "it is dangerous to operate recursively on {}\nchmod: use --no-preserve-root to override this failsafe",
-                        filename.quote()
+                        diag_quoted(filename)
                     )
                 ));
             }
@@ -365,10 +365,10 @@ impl Chmoder {
                     // quoted, but GNU's tests expect them to always be quoted
                     return Err(USimpleError::new(
                         1,
-                        format!("{}: Permission denied", file.quote()),
+                        format!("{}: Permission denied", diag_quoted(file)),
                     ));
                 } else {
-                    return Err(USimpleError::new(1, format!("{}: {}", file.quote(), err)));
+                    return Err(USimpleError::new(1, format!("{}: {}", diag_quoted(file), err)));
                 }
             }
         };
@@ -17,6 +17,7 @@ use uucore::entries::{grp2gid, usr2uid, Locate, Passwd};
 use uucore::error::{set_exit_code, UClapError, UResult, UUsageError};
 use uucore::fs::{canonicalize, MissingHandling, ResolveMode};
 use uucore::libc::{self, chroot, setgid, setgroups, setuid};
+use uucore::process::CommandExt;
 use uucore::{format_usage, help_about, help_usage, show};
 
 static ABOUT: &str = help_about!("chroot.md");
@@ -213,10 +214,15 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     // NOTE: Tests can only trigger code beyond this point if they're invoked with root permissions
     set_context(&options)?;
 
-    let pstatus = match process::Command::new(chroot_command)
-        .args(chroot_args)
-        .status()
-    {
+    let mut chroot_cmd = process::Command::new(chroot_command);
+    chroot_cmd.args(chroot_args);
+    if options.userspec.is_some() {
+        // Do not leak the invoking user's environment into the command
+        // that runs as another user
+        chroot_cmd = chroot_cmd.with_env_reset();
+    }
+
+    let pstatus = match chroot_cmd.status() {
         Ok(status) => status,
         Err(e) => {
             return Err(if e.kind() == std::io::ErrorKind::NotFound {
@@ -35,6 +35,15 @@ enum OutputFormat {
     Hexadecimal,
     Raw,
     Base64,
+    Json,
+}
+
+/// One entry of the array emitted with `--format=json`.
+struct JsonEntry {
+    file: String,
+    checksum: String,
+    algorithm: &'static str,
+    size: usize,
 }
 
 struct Options {
@@ -64,6 +73,7 @@ where
         return Err(Box::new(ChecksumError::RawMultipleFiles));
     }
 
+    let mut json_entries = Vec::new();
     for filename in files {
         let filename = Path::new(filename);
         let stdin_buf;
@@ -99,6 +109,20 @@ where
             digest_reader(&mut options.digest, &mut file, false, options.output_bits)
                 .map_err_context(|| "failed to read input".to_string())?;
 
+        if options.output_format == OutputFormat::Json {
+            json_entries.push(JsonEntry {
+                file: if not_file {
+                    "<stdin>".to_string()
+                } else {
+                    filename.to_string_lossy().into_owned()
+                },
+                checksum: sum_hex,
+                algorithm: options.algo_name,
+                size: sz,
+            });
+            continue;
+        }
+
         let sum = match options.output_format {
             OutputFormat::Raw => {
                 let bytes = match options.algo_name {
@@ -112,6 +136,7 @@ where
                 stdout().write_all(&bytes)?;
                 return Ok(());
             }
+            OutputFormat::Json => unreachable!("handled above"),
             OutputFormat::Hexadecimal => sum_hex,
             OutputFormat::Base64 => match options.algo_name {
                 ALGORITHM_OPTIONS_CRC
@@ -183,9 +208,59 @@ where
         print!("{after_filename}{}", options.line_ending);
     }
 
+    if options.output_format == OutputFormat::Json {
+        use std::io::IsTerminal;
+        print!("{}", format_json(&json_entries, stdout().is_terminal()));
+    }
+
     Ok(())
 }
 
+/// Serialize the collected digests as a JSON array, pretty-printed for a
+/// terminal and compact otherwise. The result ends with a newline.
+fn format_json(entries: &[JsonEntry], pretty: bool) -> String {
+    // No space after the colon in compact mode.
+    let sep = if pretty { ": " } else { ":" };
+    let objects: Vec<String> = entries
+        .iter()
+        .map(|entry| {
+            let fields = [
+                format!("\"file\"{sep}\"{}\"", json_escape(&entry.file)),
+                format!("\"checksum\"{sep}\"{}\"", json_escape(&entry.checksum)),
+                format!("\"algorithm\"{sep}\"{}\"", json_escape(entry.algorithm)),
+                format!("\"size\"{sep}{}", entry.size),
+            ];
+            if pretty {
+                format!("  {{\n    {}\n  }}", fields.join(",\n    "))
+            } else {
+                format!("{{{}}}", fields.join(","))
+            }
+        })
+        .collect();
+    if pretty && !objects.is_empty() {
+        format!("[\n{}\n]\n", objects.join(",\n"))
+    } else {
+        format!("[{}]\n", objects.join(","))
+    }
+}
+
+/// Escape a string for use inside a JSON string literal.
+fn json_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 mod options {
     pub const ALGORITHM: &str = "algorithm";
     pub const FILE: &str = "file";
@@ -194,6 +269,7 @@ mod options {
     pub const LENGTH: &str = "length";
     pub const RAW: &str = "raw";
     pub const BASE64: &str = "base64";
+    pub const FORMAT: &str = "format";
     pub const CHECK: &str = "check";
     pub const STRICT: &str = "strict";
     pub const TEXT: &str = "text";
@@ -201,6 +277,7 @@ mod options {
     pub const STATUS: &str = "status";
     pub const WARN: &str = "warn";
     pub const IGNORE_MISSING: &str = "ignore-missing";
+    pub const WARN_MISSING: &str = "warn-missing";
     pub const QUIET: &str = "quiet";
     pub const ZERO: &str = "zero";
 }
@@ -304,6 +381,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         let status = matches.get_flag(options::STATUS);
         let warn = matches.get_flag(options::WARN);
         let ignore_missing = matches.get_flag(options::IGNORE_MISSING);
+        let warn_missing = matches.get_flag(options::WARN_MISSING);
         let quiet = matches.get_flag(options::QUIET);
         let tag = matches.get_flag(options::TAG);
 
@@ -329,6 +407,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         let opts = ChecksumOptions {
             binary: binary_flag,
             ignore_missing,
+            warn_missing,
             strict,
             verbose,
         };
@@ -345,6 +424,9 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         OutputFormat::Raw
     } else if matches.get_flag(options::BASE64) {
         OutputFormat::Base64
+    } else if matches.get_one::<String>(options::FORMAT).is_some() {
+        // "json" is the only accepted value, enforced by clap
+        OutputFormat::Json
     } else {
         OutputFormat::Hexadecimal
     };
@@ -443,6 +525,16 @@ pub fn uu_app() -> Command {
                 // GNU cksum does not permit these flags to be combined:
                 .conflicts_with(options::RAW),
         )
+        .arg(
+            Arg::new(options::FORMAT)
+                .long(options::FORMAT)
+                .help("emit the digests in the given format; only 'json' is supported")
+                .value_name("FORMAT")
+                .value_parser(["json"])
+                // Verification has its own output; the format would be
+                // silently ignored otherwise.
+                .conflicts_with_all([options::RAW, options::BASE64, options::CHECK]),
+        )
         .arg(
             Arg::new(options::TEXT)
                 .long(options::TEXT)
@@ -487,6 +579,15 @@ pub fn uu_app() -> Command {
                 .help("don't fail or report status for missing files")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new(options::WARN_MISSING)
+                .long(options::WARN_MISSING)
+                .help(
+                    "warn about files in the checksum file's directory \
+                    that the checksum file does not list",
+                )
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             Arg::new(options::ZERO)
                 .long(options::ZERO)
@@ -502,6 +603,7 @@ pub fn uu_app() -> Command {
 #[cfg(test)]
 mod tests {
     use super::had_reset;
+    use super::{format_json, json_escape, JsonEntry};
     use crate::calculate_blake2b_length;
     use crate::prompt_asterisk;
     use std::ffi::OsString;
@@ -571,6 +673,36 @@ mod tests {
         assert!(!prompt_asterisk(false, false, false));
     }
 
+    #[test]
+    fn test_json_escape() {
+        assert_eq!(json_escape("plain"), "plain");
+        assert_eq!(json_escape("a\"b"), "a\\\"b");
+        assert_eq!(json_escape("a\\b"), "a\\\\b");
+        assert_eq!(json_escape("a\nb\tc"), "a\\nb\\tc");
+        assert_eq!(json_escape("a\u{1}b"), "a\\u0001b");
+    }
+
+    #[test]
+    fn test_format_json() {
+        let entries = [JsonEntry {
+            file: "f\"1".to_string(),
+            checksum: "4294967295".to_string(),
+            algorithm: "crc",
+            size: 6,
+        }];
+        assert_eq!(
+            format_json(&entries, false),
+            "[{\"file\":\"f\\\"1\",\"checksum\":\"4294967295\",\"algorithm\":\"crc\",\"size\":6}]\n"
+        );
+        assert_eq!(
+            format_json(&entries, true),
+            "[\n  {\n    \"file\": \"f\\\"1\",\n    \"checksum\": \"4294967295\",\n    \
+             \"algorithm\": \"crc\",\n    \"size\": 6\n  }\n]\n"
+        );
+        assert_eq!(format_json(&[], false), "[]\n");
+        assert_eq!(format_json(&[], true), "[]\n");
+    }
+
     #[test]
     fn test_calculate_length() {
         assert_eq!(calculate_blake2b_length(256).unwrap(), Some(32));
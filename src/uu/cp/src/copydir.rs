@@ -15,7 +15,6 @@ use std::io;
 use std::path::{Path, PathBuf, StripPrefixError};
 
 use indicatif::ProgressBar;
-use uucore::display::Quotable;
 use uucore::error::UIoError;
 use uucore::fs::{
     canonicalize, path_ends_with_terminator, FileInformation, MissingHandling, ResolveMode,
@@ -25,6 +24,7 @@ use uucore::show_error;
 use uucore::uio_error;
 use walkdir::{DirEntry, WalkDir};
 
+use uucore::display::diag_quoted;
 use crate::{
     aligned_ancestors, context_for, copy_attributes, copy_file, copy_link, CopyResult, Error,
     Options,
@@ -311,7 +311,7 @@ fn copy_direntry(
                     show!(uio_error!(
                         e,
                         "cannot open {} for reading",
-                        source_relative.quote(),
+                        diag_quoted(&source_relative),
                     ));
                 }
                 Err(e) => return Err(e),
@@ -355,15 +355,15 @@ pub(crate) fn copy_directory(
     }
 
     if !options.recursive {
-        return Err(format!("-r not specified; omitting directory {}", root.quote()).into());
+        return Err(format!("-r not specified; omitting directory {}", diag_quoted(root)).into());
     }
 
     // check if root is a prefix of target
     if path_has_prefix(target, root)? {
         return Err(format!(
             "cannot copy a directory, {}, into itself, {}",
-            root.quote(),
-            target.join(root.file_name().unwrap()).quote()
+            diag_quoted(root),
+            diag_quoted(&target.join(root.file_name().unwrap()))
         )
         .into());
     }
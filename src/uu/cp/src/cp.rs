@@ -28,7 +28,7 @@ use libc::mkfifo;
 use quick_error::ResultExt;
 
 use platform::copy_on_write;
-use uucore::display::Quotable;
+use uucore::display::{diag_quoted, Quotable};
 use uucore::error::{set_exit_code, UClapError, UError, UResult, UUsageError};
 use uucore::fs::{
     are_hardlinks_to_same_file, canonicalize, get_filename, is_symlink_loop, normalize_path,
@@ -1285,7 +1285,7 @@ pub fn copy(sources: &[PathBuf], target: &Path, options: &Options) -> CopyResult
             };
             show_warning!(
                 "source {file_type} {} specified more than once",
-                source.quote()
+                diag_quoted(source)
             );
         } else {
             let dest = construct_dest_path(source, target, target_type, options)
@@ -1352,7 +1352,7 @@ fn construct_dest_path(
     if options.no_target_dir && target.is_dir() {
         return Err(format!(
             "cannot overwrite directory {} with non-directory",
-            target.quote()
+            diag_quoted(target)
         )
         .into());
     }
@@ -1570,7 +1570,7 @@ pub(crate) fn copy_attributes(
     dest: &Path,
     attributes: &Attributes,
 ) -> CopyResult<()> {
-    let context = &*format!("{} -> {}", source.quote(), dest.quote());
+    let context = &*format!("{} -> {}", diag_quoted(source), diag_quoted(dest));
     let source_metadata = fs::symlink_metadata(source).context(context)?;
 
     // Ownership must be changed first to avoid interfering with mode change.
@@ -1684,16 +1684,16 @@ fn symlink_file(
     {
         std::os::unix::fs::symlink(source, dest).context(format!(
             "cannot create symlink {} to {}",
-            get_filename(dest).unwrap_or("invalid file name").quote(),
-            get_filename(source).unwrap_or("invalid file name").quote()
+            diag_quoted(get_filename(dest).unwrap_or("invalid file name")),
+            diag_quoted(get_filename(source).unwrap_or("invalid file name"))
         ))?;
     }
     #[cfg(windows)]
     {
         std::os::windows::fs::symlink_file(source, dest).context(format!(
             "cannot create symlink {} to {}",
-            get_filename(dest).unwrap_or("invalid file name").quote(),
-            get_filename(source).unwrap_or("invalid file name").quote()
+            diag_quoted(get_filename(dest).unwrap_or("invalid file name")),
+            diag_quoted(get_filename(source).unwrap_or("invalid file name"))
         ))?;
     }
     if let Ok(file_info) = FileInformation::from_path(dest, false) {
@@ -1703,7 +1703,7 @@ fn symlink_file(
 }
 
 fn context_for(src: &Path, dest: &Path) -> String {
-    format!("{} -> {}", src.quote(), dest.quote())
+    format!("{} -> {}", diag_quoted(src), diag_quoted(dest))
 }
 
 /// Implements a simple backup copy for the destination file .
@@ -1776,7 +1776,7 @@ fn handle_existing_dest(
     // Disallow copying a file to itself, unless `--force` and
     // `--backup` are both specified.
     if is_forbidden_to_copy_to_same_file(source, dest, options, source_in_command_line) {
-        return Err(format!("{} and {} are the same file", source.quote(), dest.quote()).into());
+        return Err(format!("{} and {} are the same file", diag_quoted(source), diag_quoted(dest)).into());
     }
 
     if options.update != UpdateMode::ReplaceIfOlder {
@@ -1789,8 +1789,8 @@ fn handle_existing_dest(
         if paths_refer_to_same_file(source, &backup_path, true) {
             return Err(format!(
                 "backing up {} might destroy source;  {} not copied",
-                dest.quote(),
-                source.quote()
+                diag_quoted(dest),
+                diag_quoted(source)
             )
             .into());
         } else {
@@ -1856,7 +1856,7 @@ fn delete_dest_if_needed_and_allowed(
                                 &FileInformation::from_path(
                                     source,
                                     options.dereference(source_in_command_line)
-                                ).context(format!("cannot stat {}", source.quote()))?
+                                ).context(format!("cannot stat {}", diag_quoted(source)))?
                             )
                 }
             }
@@ -1999,8 +1999,8 @@ fn handle_copy_mode(
             }
             .context(format!(
                 "cannot create hard link {} to {}",
-                get_filename(dest).unwrap_or("invalid file name").quote(),
-                get_filename(source).unwrap_or("invalid file name").quote()
+                diag_quoted(get_filename(dest).unwrap_or("invalid file name")),
+                diag_quoted(get_filename(source).unwrap_or("invalid file name"))
             ))?;
         }
         CopyMode::Copy => {
@@ -2266,7 +2266,7 @@ fn copy_file(
     {
         return Err(format!(
             "cannot change attribute {}: Source file is a non regular file",
-            dest.quote()
+            diag_quoted(dest)
         )
         .into());
     }
@@ -2281,7 +2281,7 @@ fn copy_file(
         // in the destination tree.
         if let Some(new_source) = copied_files.get(
             &FileInformation::from_path(source, options.dereference(source_in_command_line))
-                .context(format!("cannot stat {}", source.quote()))?,
+                .context(format!("cannot stat {}", diag_quoted(source)))?,
         ) {
             std::fs::hard_link(new_source, dest)?;
             return Ok(());
@@ -2301,7 +2301,7 @@ fn copy_file(
         // this is just for gnu tests compatibility
         result.map_err(|err| {
             if err.to_string().contains("No such file or directory") {
-                return format!("cannot stat {}: No such file or directory", source.quote());
+                return format!("cannot stat {}: No such file or directory", diag_quoted(source));
             }
             err.to_string()
         })?
@@ -2478,7 +2478,7 @@ fn copy_fifo(dest: &Path, overwrite: OverwriteMode, debug: bool) -> CopyResult<(
     let name = CString::new(dest.as_os_str().as_bytes()).unwrap();
     let err = unsafe { mkfifo(name.as_ptr(), 0o666) };
     if err == -1 {
-        return Err(format!("cannot create fifo {}: File exists", dest.quote()).into());
+        return Err(format!("cannot create fifo {}: File exists", diag_quoted(dest)).into());
     }
     Ok(())
 }
@@ -2502,11 +2502,11 @@ fn copy_link(
 pub fn verify_target_type(target: &Path, target_type: &TargetType) -> CopyResult<()> {
     match (target_type, target.is_dir()) {
         (&TargetType::Directory, false) => {
-            Err(format!("target: {} is not a directory", target.quote()).into())
+            Err(format!("target: {} is not a directory", diag_quoted(target)).into())
         }
         (&TargetType::File, true) => Err(format!(
             "cannot overwrite directory {} with non-directory",
-            target.quote()
+            diag_quoted(target)
         )
         .into()),
         _ => Ok(()),
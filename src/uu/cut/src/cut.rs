@@ -9,11 +9,12 @@ use bstr::io::BufReadExt;
 use clap::{builder::ValueParser, crate_version, Arg, ArgAction, ArgMatches, Command};
 use std::ffi::OsString;
 use std::fs::File;
-use std::io::{stdin, stdout, BufRead, BufReader, BufWriter, IsTerminal, Read, Write};
+use std::io::{stdin, stdout, BufReader, BufWriter, IsTerminal, Read, Write};
 use std::path::Path;
 use uucore::display::Quotable;
 use uucore::error::{set_exit_code, FromIo, UResult, USimpleError};
 use uucore::line_ending::LineEnding;
+use uucore::lines::ByteLines;
 use uucore::os_str_as_bytes;
 
 use self::searcher::Searcher;
@@ -277,7 +278,9 @@ fn cut_fields_newline_char_delim<R: Read>(
     let buf_in = BufReader::new(reader);
     let mut out = stdout_writer();
 
-    let segments: Vec<_> = buf_in.split(newline_char).filter_map(|x| x.ok()).collect();
+    let segments: Vec<_> = ByteLines::with_delimiter(buf_in, newline_char)
+        .filter_map(|x| x.ok())
+        .collect();
     let mut print_delim = false;
 
     for &Range { low, high } in ranges {
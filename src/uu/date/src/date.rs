@@ -6,7 +6,7 @@
 // spell-checker:ignore (chrono) Datelike Timelike ; (format) DATEFILE MMDDhhmm ; (vars) datetime datetimes
 
 use chrono::format::{Item, StrftimeItems};
-use chrono::{DateTime, FixedOffset, Local, Offset, TimeDelta, Utc};
+use chrono::{DateTime, FixedOffset, Local, Offset, TimeDelta, TimeZone, Utc};
 #[cfg(windows)]
 use chrono::{Datelike, Timelike};
 use clap::{crate_version, Arg, ArgAction, Command};
@@ -41,6 +41,7 @@ const OPT_FILE: &str = "file";
 const OPT_DEBUG: &str = "debug";
 const OPT_ISO_8601: &str = "iso-8601";
 const OPT_RFC_EMAIL: &str = "rfc-email";
+const OPT_RFC_EMAIL_2: &str = "rfc-2822";
 const OPT_RFC_3339: &str = "rfc-3339";
 const OPT_SET: &str = "set";
 const OPT_REFERENCE: &str = "reference";
@@ -344,6 +345,7 @@ pub fn uu_app() -> Command {
             Arg::new(OPT_RFC_EMAIL)
                 .short('R')
                 .long(OPT_RFC_EMAIL)
+                .alias(OPT_RFC_EMAIL_2)
                 .help(RFC_5322_HELP_STRING)
                 .action(ArgAction::SetTrue),
         )
@@ -407,11 +409,44 @@ fn make_format_string(settings: &Settings) -> &str {
     }
 }
 
+/// Parse a timestamp of the form `@SECONDS.FRACTION` into a `DateTime`.
+///
+/// `parse_datetime` already handles plain `@SECONDS` timestamps; this adds
+/// the fractional variant accepted by GNU `date`, e.g. `@1628124123.456789`.
+/// The fraction is interpreted with nanosecond precision; extra digits are
+/// ignored. Returns `None` if `s` is not a fractional timestamp.
+fn parse_timestamp(s: &str) -> Option<DateTime<FixedOffset>> {
+    let s = s.strip_prefix('@')?;
+    let (seconds, fraction) = s.split_once('.')?;
+
+    let negative = seconds.starts_with('-');
+    let mut seconds: i64 = seconds.parse().ok()?;
+
+    if !fraction.is_empty() && !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    // Pad or truncate the fraction to exactly nine digits (nanoseconds).
+    let mut nanoseconds: u32 = format!("{fraction:0<9.9}").parse().ok()?;
+
+    // `timestamp_opt` expects the nanoseconds to be added to the seconds, so
+    // a negative timestamp like `@-1.5` maps to (-2 s, 500 000 000 ns).
+    if negative && nanoseconds != 0 {
+        seconds = seconds.checked_sub(1)?;
+        nanoseconds = 1_000_000_000 - nanoseconds;
+    }
+
+    let date = Utc.timestamp_opt(seconds, nanoseconds).single()?;
+    Some(date.with_timezone(&Local).fixed_offset())
+}
+
 /// Parse a `String` into a `DateTime`.
 /// If it fails, return a tuple of the `String` along with its `ParseError`.
 fn parse_date<S: AsRef<str> + Clone>(
     s: S,
 ) -> Result<DateTime<FixedOffset>, (String, parse_datetime::ParseDateTimeError)> {
+    if let Some(date) = parse_timestamp(s.as_ref()) {
+        return Ok(date);
+    }
     parse_datetime::parse_datetime(s.as_ref()).map_err(|e| (s.as_ref().into(), e))
 }
 
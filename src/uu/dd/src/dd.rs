@@ -58,7 +58,7 @@ use uucore::error::{set_exit_code, USimpleError};
 use uucore::error::{FromIo, UResult};
 #[cfg(target_os = "linux")]
 use uucore::show_if_err;
-use uucore::{format_usage, help_about, help_section, help_usage, show_error};
+use uucore::{format_usage, help_about, help_section, help_usage, show_error, show_warning};
 
 const ABOUT: &str = help_about!("dd.md");
 const AFTER_HELP: &str = help_section!("after help", "dd.md");
@@ -344,6 +344,8 @@ impl<'a> Input<'a> {
                     "setting flags for 'standard input': Not a directory",
                 ));
             }
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            warn_on_sector_size_mismatch(f, settings);
         };
         if settings.skip > 0 {
             src.skip(settings.skip)?;
@@ -366,6 +368,9 @@ impl<'a> Input<'a> {
                 .map_err_context(|| format!("failed to open {}", filename.quote()))?
         };
 
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        warn_on_sector_size_mismatch(&src, settings);
+
         let mut src = Source::File(src);
         if settings.skip > 0 {
             src.skip(settings.skip)?;
@@ -388,6 +393,35 @@ impl<'a> Input<'a> {
     }
 }
 
+/// Warn if the input is a block device whose sector size does not divide
+/// the configured input block size.
+///
+/// Reads from a block device are served in multiples of its sector size,
+/// so an `ibs` that is not a multiple of it gives surprising `count=` and
+/// `skip=` semantics.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn warn_on_sector_size_mismatch(f: &File, settings: &Settings) {
+    use std::os::unix::fs::FileTypeExt;
+
+    let is_block_device = f
+        .metadata()
+        .is_ok_and(|metadata| metadata.file_type().is_block_device());
+    if !is_block_device {
+        return;
+    }
+    let mut sector_size: libc::c_int = 0;
+    if unsafe { libc::ioctl(f.as_raw_fd(), libc::BLKSSZGET, &mut sector_size) } != 0 {
+        return;
+    }
+    if sector_size > 0 && settings.ibs % sector_size as usize != 0 {
+        show_warning!(
+            "input block size {} is not a multiple of the device sector size {}",
+            settings.ibs,
+            sector_size
+        );
+    }
+}
+
 #[cfg(any(target_os = "linux", target_os = "android"))]
 fn make_linux_iflags(iflags: &IFlags) -> Option<libc::c_int> {
     let mut flag = 0;
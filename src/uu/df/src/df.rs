@@ -64,6 +64,13 @@ static OUTPUT_FIELD_LIST: [&str; 12] = [
 struct Options {
     show_local_fs: bool,
     show_all_fs: bool,
+
+    /// Whether the user named specific files on the command line.
+    ///
+    /// Filesystems that would normally be hidden, like pseudo
+    /// filesystems with zero blocks, are still reported when a file on
+    /// them was explicitly requested.
+    show_listed_fs: bool,
     human_readable: Option<HumanReadable>,
     block_size: BlockSize,
     header_mode: HeaderMode,
@@ -95,6 +102,7 @@ impl Default for Options {
         Self {
             show_local_fs: Default::default(),
             show_all_fs: Default::default(),
+            show_listed_fs: Default::default(),
             block_size: BlockSize::default(),
             human_readable: Option::default(),
             header_mode: HeaderMode::default(),
@@ -179,6 +187,7 @@ impl Options {
         Ok(Self {
             show_local_fs: matches.get_flag(OPT_LOCAL),
             show_all_fs: matches.get_flag(OPT_ALL),
+            show_listed_fs: matches.contains_id(OPT_PATHS),
             sync: matches.get_flag(OPT_SYNC),
             block_size: read_block_size(matches).map_err(|e| match e {
                 ParseSizeError::InvalidSuffix(s) => OptionsError::InvalidSuffix(s),
@@ -242,8 +251,9 @@ fn is_included(mi: &MountInfo, opt: &Options) -> bool {
         return false;
     }
 
-    // Don't show pseudo filesystems unless `--all` has been given.
-    if mi.dummy && !opt.show_all_fs {
+    // Don't show pseudo filesystems unless `--all` has been given or
+    // the user named a file on the filesystem.
+    if mi.dummy && !opt.show_all_fs && !opt.show_listed_fs {
         return false;
     }
 
@@ -380,13 +390,15 @@ where
 {
     // The list of all mounted filesystems.
     //
-    // Filesystems marked as `dummy` or of type "lofs" are not
-    // considered. The "lofs" filesystem is a loopback
-    // filesystem present on Solaris and FreeBSD systems. It
-    // is similar to a symbolic link.
+    // Filesystems of type "lofs" are not considered. The "lofs"
+    // filesystem is a loopback filesystem present on Solaris and
+    // FreeBSD systems. It is similar to a symbolic link. Dummy
+    // filesystems (like "proc" or "cgroup2") remain candidates:
+    // naming a file on one of them must report that filesystem, even
+    // though it is hidden from the unrestricted listing.
     let mounts: Vec<MountInfo> = filter_mount_list(read_fs_list()?, opt)
         .into_iter()
-        .filter(|mi| mi.fs_type != "lofs" && !mi.dummy)
+        .filter(|mi| mi.fs_type != "lofs")
         .collect();
 
     let mut result = vec![];
@@ -113,10 +113,13 @@ where
 
     maybe_mount_point
         .or_else(|| {
+            // Prefer the longest matching mount directory; if a dummy
+            // filesystem and a real one are mounted at the same place, the
+            // real one wins, as in GNU df.
             mounts
                 .iter()
                 .filter(|mi| path.starts_with(&mi.mount_dir))
-                .max_by_key(|mi| mi.mount_dir.len())
+                .max_by_key(|mi| (mi.mount_dir.len(), !mi.dummy))
         })
         .ok_or(FsError::MountMissing)
 }
@@ -291,6 +294,24 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_dummy_mount_considered() {
+            let mut dummy = mount_info("/proc");
+            dummy.dummy = true;
+            let mounts = [mount_info("/"), dummy];
+            let actual = mount_info_from_path(&mounts, "/proc", false).unwrap();
+            assert!(mount_info_eq(actual, &mounts[1]));
+        }
+
+        #[test]
+        fn test_dummy_mount_loses_tie() {
+            let mut dummy = mount_info("/foo");
+            dummy.dummy = true;
+            let mounts = [dummy, mount_info("/foo")];
+            let actual = mount_info_from_path(&mounts, "/foo", false).unwrap();
+            assert!(mount_info_eq(actual, &mounts[1]));
+        }
+
         #[test]
         // clippy::assigning_clones added with Rust 1.78
         // Rust version = 1.76 on OpenBSD stable/7.5
@@ -387,9 +387,9 @@ impl Table {
 
         for filesystem in filesystems {
             // If the filesystem is not empty, or if the options require
-            // showing all filesystems, then print the data as a row in
-            // the output table.
-            if options.show_all_fs || filesystem.usage.blocks > 0 {
+            // showing all filesystems, or if it was named on the command
+            // line, then print the data as a row in the output table.
+            if options.show_all_fs || options.show_listed_fs || filesystem.usage.blocks > 0 {
                 let row = Row::from(filesystem);
                 let fmt = RowFormatter::new(&row, options, false);
                 let values = fmt.get_values();
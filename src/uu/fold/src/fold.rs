@@ -161,8 +161,22 @@ fn fold_file_bytewise<T: Read>(mut file: BufReader<T>, spaces: bool, width: usiz
 
         while i < len {
             let width = if len - i >= width { width } else { len - i };
+            // Never split in the middle of a multi-byte character: back the
+            // split point up to the last complete character before the byte
+            // limit. A single character wider than the limit is emitted
+            // whole, since it cannot be split at a byte boundary.
+            let mut end = i + width;
+            while !line.is_char_boundary(end) {
+                end -= 1;
+            }
+            if end == i {
+                end = i + width;
+                while end < len && !line.is_char_boundary(end) {
+                    end += 1;
+                }
+            }
             let slice = {
-                let slice = &line[i..i + width];
+                let slice = &line[i..end];
                 if spaces && i + width < len {
                     match slice.rfind(|c: char| c.is_whitespace() && c != '\r') {
                         Some(m) => &slice[..=m],
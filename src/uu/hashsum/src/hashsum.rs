@@ -10,6 +10,7 @@ use clap::crate_version;
 use clap::value_parser;
 use clap::ArgAction;
 use clap::{Arg, ArgMatches, Command};
+use rayon::prelude::*;
 use std::ffi::{OsStr, OsString};
 use std::fs::File;
 use std::io::{stdin, BufReader, Read};
@@ -26,7 +27,8 @@ use uucore::checksum::ChecksumError;
 use uucore::checksum::ChecksumOptions;
 use uucore::checksum::ChecksumVerbose;
 use uucore::checksum::HashAlgorithm;
-use uucore::error::{FromIo, UResult};
+use uucore::error::{FromIo, UResult, USimpleError};
+use uucore::progress::progress_bar;
 use uucore::sum::{Digest, Sha3_224, Sha3_256, Sha3_384, Sha3_512, Shake128, Shake256};
 use uucore::{format_usage, help_about, help_usage};
 
@@ -36,7 +38,7 @@ const USAGE: &str = help_usage!("hashsum.md");
 
 struct Options {
     algoname: &'static str,
-    digest: Box<dyn Digest + 'static>,
+    create_fn: Box<dyn Fn() -> Box<dyn Digest + 'static> + Send + Sync>,
     binary: bool,
     //check: bool,
     tag: bool,
@@ -48,6 +50,8 @@ struct Options {
     output_bits: usize,
     zero: bool,
     //ignore_missing: bool,
+    parallel: usize,
+    progress: bool,
 }
 
 /// Creates a hasher instance based on the command-line flags.
@@ -59,10 +63,10 @@ struct Options {
 /// # Returns
 ///
 /// Returns a UResult of a tuple containing the algorithm name, the hasher instance, and
-/// the output length in bits or an Err if multiple hash algorithms are specified or if a
-/// required flag is missing.
+/// the output length in bits, `None` if no algorithm flag was given, or an Err
+/// if multiple hash algorithms are specified or if a required flag is missing.
 #[allow(clippy::cognitive_complexity)]
-fn create_algorithm_from_flags(matches: &ArgMatches) -> UResult<HashAlgorithm> {
+fn create_algorithm_from_flags(matches: &ArgMatches) -> UResult<Option<HashAlgorithm>> {
     let mut alg: Option<HashAlgorithm> = None;
 
     let mut set_or_err = |new_alg: HashAlgorithm| -> UResult<()> {
@@ -150,11 +154,7 @@ fn create_algorithm_from_flags(matches: &ArgMatches) -> UResult<HashAlgorithm> {
         };
     }
 
-    if alg.is_none() {
-        return Err(ChecksumError::NeedAlgorithmToHash.into());
-    }
-
-    Ok(alg.unwrap())
+    Ok(alg)
 }
 
 // TODO: return custom error type
@@ -195,10 +195,12 @@ pub fn uumain(mut args: impl uucore::Args) -> UResult<()> {
         None => None,
     };
 
+    // With --check, the algorithm can also be deduced from the checksum
+    // file, so it does not need to be given on the command line.
     let algo = if is_hashsum_bin {
         create_algorithm_from_flags(&matches)?
     } else {
-        detect_algo(&binary_name, length)?
+        Some(detect_algo(&binary_name, length)?)
     };
 
     let binary = if matches.get_flag("binary") {
@@ -247,32 +249,39 @@ pub fn uumain(mut args: impl uucore::Args) -> UResult<()> {
         let opts = ChecksumOptions {
             binary,
             ignore_missing,
+            warn_missing: false,
             strict,
             verbose,
         };
 
+        let (algo_name, algo_bits) = match &algo {
+            Some(algo) => (Some(algo.name), Some(algo.bits)),
+            None => (None, None),
+        };
+
         // Execute the checksum validation
-        return perform_checksum_validation(
-            input.iter().copied(),
-            Some(algo.name),
-            Some(algo.bits),
-            opts,
-        );
+        return perform_checksum_validation(input.iter().copied(), algo_name, algo_bits, opts);
     } else if quiet {
         return Err(ChecksumError::QuietNotCheck.into());
     } else if strict {
         return Err(ChecksumError::StrictNotCheck.into());
     }
 
+    let algo = algo.ok_or(ChecksumError::NeedAlgorithmToHash)?;
+
     let nonames = *matches
         .try_get_one("no-names")
         .unwrap_or(None)
         .unwrap_or(&false);
     let zero = matches.get_flag("zero");
 
+    let parallel = matches
+        .get_one::<u64>(options::PARALLEL)
+        .map_or(1, |&n| n as usize);
+
     let opts = Options {
         algoname: algo.name,
-        digest: (algo.create_fn)(),
+        create_fn: algo.create_fn,
         output_bits: algo.bits,
         binary,
         tag: matches.get_flag("tag"),
@@ -282,6 +291,10 @@ pub fn uumain(mut args: impl uucore::Args) -> UResult<()> {
         //warn,
         zero,
         //ignore_missing,
+        parallel,
+        // --quiet cannot reach this point without --check, but keep the
+        // guard in case that ever changes.
+        progress: matches.get_flag(options::PROGRESS) && !quiet,
     };
 
     // Show the hashsum of the input
@@ -306,6 +319,8 @@ mod options {
     pub const STATUS: &str = "status";
     pub const WARN: &str = "warn";
     pub const QUIET: &str = "quiet";
+    pub const PARALLEL: &str = "parallel";
+    pub const PROGRESS: &str = "progress";
 }
 
 pub fn uu_app_common() -> Command {
@@ -389,6 +404,25 @@ pub fn uu_app_common() -> Command {
                 .action(ArgAction::SetTrue)
                 .overrides_with_all([options::QUIET, options::STATUS]),
         )
+        .arg(
+            Arg::new(options::PARALLEL)
+                .long(options::PARALLEL)
+                .value_name("N")
+                .help("compute the checksums of the FILEs using up to N threads")
+                .value_parser(value_parser!(u64).range(1..)),
+        )
+        .arg(
+            Arg::new(options::PROGRESS)
+                .long(options::PROGRESS)
+                .help(
+                    "show a progress bar on stderr while hashing \
+                    (only when stderr is a terminal)",
+                )
+                .action(ArgAction::SetTrue)
+                // Worker threads would each draw their own bar on top of
+                // the others.
+                .conflicts_with(options::PARALLEL),
+        )
         .arg(
             Arg::new("zero")
                 .short('z')
@@ -515,59 +549,105 @@ fn uu_app(binary_name: &str) -> (Command, bool) {
     }
 }
 
-#[allow(clippy::cognitive_complexity)]
-fn hashsum<'a, I>(mut options: Options, files: I) -> UResult<()>
+fn hashsum<'a, I>(options: Options, files: I) -> UResult<()>
 where
     I: Iterator<Item = &'a OsStr>,
 {
-    let binary_marker = if options.binary { "*" } else { " " };
+    let files: Vec<&OsStr> = files.collect();
+    // Reading stdin from several worker threads at once would interleave
+    // the input nondeterministically, so "-" forces the sequential path.
+    if options.parallel > 1 && !files.contains(&OsStr::new("-")) {
+        return hashsum_parallel(&options, files);
+    }
+    let mut digest = (options.create_fn)();
     for filename in files {
-        let filename = Path::new(filename);
+        let line = digest_file(&options, &mut digest, Path::new(filename))?;
+        print!("{line}");
+    }
+    Ok(())
+}
 
-        let stdin_buf;
-        let file_buf;
-        let mut file = BufReader::new(if filename == OsStr::new("-") {
-            stdin_buf = stdin();
-            Box::new(stdin_buf) as Box<dyn Read>
-        } else {
-            file_buf =
-                File::open(filename).map_err_context(|| "failed to open file".to_string())?;
-            Box::new(file_buf) as Box<dyn Read>
-        });
-
-        let (sum, _) = digest_reader(
-            &mut options.digest,
-            &mut file,
-            options.binary,
-            options.output_bits,
-        )
+/// Compute the checksums of `files` on up to `options.parallel` threads.
+///
+/// The output lines are only printed once all digests are available, in the
+/// order the files were given, so the output matches a sequential run.
+fn hashsum_parallel(options: &Options, files: Vec<&OsStr>) -> UResult<()> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(options.parallel)
+        .build()
+        .map_err(|e| USimpleError::new(1, e.to_string()))?;
+    let lines: Vec<UResult<String>> = pool.install(|| {
+        files
+            .par_iter()
+            .map(|filename| {
+                let mut digest = (options.create_fn)();
+                digest_file(options, &mut digest, Path::new(filename))
+            })
+            .collect()
+    });
+    for line in lines {
+        print!("{}", line?);
+    }
+    Ok(())
+}
+
+/// Compute the checksum of one file (or of stdin for "-") and return its
+/// formatted output line, including the line terminator.
+#[allow(clippy::cognitive_complexity)]
+fn digest_file(
+    options: &Options,
+    digest: &mut Box<dyn Digest + 'static>,
+    filename: &Path,
+) -> UResult<String> {
+    let binary_marker = if options.binary { "*" } else { " " };
+
+    let input: Box<dyn Read> = if filename == OsStr::new("-") {
+        Box::new(stdin())
+    } else {
+        Box::new(File::open(filename).map_err_context(|| "failed to open file".to_string())?)
+    };
+
+    // The bar is hidden automatically when stderr is not a terminal.
+    let progress = if options.progress {
+        let total = std::fs::metadata(filename).ok().map(|m| m.len());
+        Some(progress_bar(&filename.to_string_lossy(), total))
+    } else {
+        None
+    };
+    let mut file = BufReader::new(match &progress {
+        Some(bar) => Box::new(bar.wrap_read(input)) as Box<dyn Read>,
+        None => input,
+    });
+
+    let (sum, _) = digest_reader(digest, &mut file, options.binary, options.output_bits)
         .map_err_context(|| "failed to read input".to_string())?;
-        let (escaped_filename, prefix) = escape_filename(filename);
-        if options.tag {
-            if options.algoname == "blake2b" {
-                if options.digest.output_bits() == 512 {
-                    println!("BLAKE2b ({escaped_filename}) = {sum}");
-                } else {
-                    // special case for BLAKE2b with non-default output length
-                    println!(
-                        "BLAKE2b-{} ({escaped_filename}) = {sum}",
-                        options.digest.output_bits()
-                    );
-                }
+    if let Some(bar) = progress {
+        bar.finish_and_clear();
+    }
+    let (escaped_filename, prefix) = escape_filename(filename);
+    Ok(if options.tag {
+        if options.algoname == "blake2b" {
+            if digest.output_bits() == 512 {
+                format!("BLAKE2b ({escaped_filename}) = {sum}\n")
             } else {
-                println!(
-                    "{prefix}{} ({escaped_filename}) = {sum}",
-                    options.algoname.to_ascii_uppercase()
-                );
+                // special case for BLAKE2b with non-default output length
+                format!(
+                    "BLAKE2b-{} ({escaped_filename}) = {sum}\n",
+                    digest.output_bits()
+                )
             }
-        } else if options.nonames {
-            println!("{sum}");
-        } else if options.zero {
-            // with zero, we don't escape the filename
-            print!("{sum} {binary_marker}{}\0", filename.display());
         } else {
-            println!("{prefix}{sum} {binary_marker}{escaped_filename}");
+            format!(
+                "{prefix}{} ({escaped_filename}) = {sum}\n",
+                options.algoname.to_ascii_uppercase()
+            )
         }
-    }
-    Ok(())
+    } else if options.nonames {
+        format!("{sum}\n")
+    } else if options.zero {
+        // with zero, we don't escape the filename
+        format!("{sum} {binary_marker}{}\0", filename.display())
+    } else {
+        format!("{prefix}{sum} {binary_marker}{escaped_filename}\n")
+    })
 }
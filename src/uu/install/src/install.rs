@@ -404,9 +404,10 @@ fn behavior(matches: &ArgMatches) -> UResult<Behavior> {
     let owner_id = if owner.is_empty() {
         None
     } else {
-        match usr2uid(&owner) {
-            Ok(u) => Some(u),
-            Err(_) => return Err(InstallError::InvalidUser(owner.clone()).into()),
+        // Accept a numeric uid even when it has no passwd database entry.
+        match usr2uid(&owner).ok().or_else(|| owner.parse().ok()) {
+            Some(u) => Some(u),
+            None => return Err(InstallError::InvalidUser(owner.clone()).into()),
         }
     };
 
@@ -419,9 +420,10 @@ fn behavior(matches: &ArgMatches) -> UResult<Behavior> {
     let group_id = if group.is_empty() {
         None
     } else {
-        match grp2gid(&group) {
-            Ok(g) => Some(g),
-            Err(_) => return Err(InstallError::InvalidGroup(group.clone()).into()),
+        // Accept a numeric gid even when it has no group database entry.
+        match grp2gid(&group).ok().or_else(|| group.parse().ok()) {
+            Some(g) => Some(g),
+            None => return Err(InstallError::InvalidGroup(group.clone()).into()),
         }
     };
 
@@ -6,12 +6,12 @@
 // spell-checker:ignore (ToDO) signalname pids killpg
 
 use clap::{crate_version, Arg, ArgAction, Command};
-use nix::sys::signal::{self, Signal};
-use nix::unistd::Pid;
 use std::io::Error;
+use std::thread;
+use std::time::{Duration, Instant};
 use uucore::display::Quotable;
 use uucore::error::{FromIo, UResult, USimpleError};
-use uucore::signals::{signal_by_name_or_value, signal_name_by_value, ALL_SIGNALS};
+use uucore::signals::{signal_name, signal_number, ALL_SIGNALS};
 use uucore::{format_usage, help_about, help_usage, show};
 
 static ABOUT: &str = help_about!("kill.md");
@@ -20,13 +20,14 @@ const USAGE: &str = help_usage!("kill.md");
 // When the -l option is selected, the program displays the type of signal related to a certain
 // value or string. In case of a value, the program should control the lower 8 bits, but there is
 // a particular case in which if the value is in range [128, 159], it is translated to a signal
-const OFFSET: usize = 128;
+const OFFSET: i32 = 128;
 
 pub mod options {
     pub static PIDS_OR_SIGNALS: &str = "pids_or_signals";
     pub static LIST: &str = "list";
     pub static TABLE: &str = "table";
     pub static SIGNAL: &str = "signal";
+    pub static TIMEOUT: &str = "timeout";
 }
 
 #[derive(Clone, Copy)]
@@ -58,24 +59,30 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
 
     match mode {
         Mode::Kill => {
+            let timeout = match matches.get_one::<String>(options::TIMEOUT) {
+                Some(time) => Some(
+                    uucore::parse_time::from_str(time)
+                        .map_err(|err| USimpleError::new(1, err))?,
+                ),
+                None => None,
+            };
+
+            let mut pids_or_signals = pids_or_signals;
             let sig = if let Some(signal) = obs_signal {
                 signal
             } else if let Some(signal) = matches.get_one::<String>(options::SIGNAL) {
                 parse_signal_value(signal)?
+            } else if let Some(signal) = pids_or_signals
+                .first()
+                .filter(|s| timeout.is_some() && s.parse::<i32>().is_err())
+                .and_then(|s| signal_number(s))
+            {
+                // `kill --timeout=5s TERM PID` puts the signal in the first
+                // positional argument
+                pids_or_signals.remove(0);
+                signal
             } else {
-                15_usize //SIGTERM
-            };
-
-            let sig_name = signal_name_by_value(sig);
-            // Signal does not support converting from EXIT
-            // Instead, nix::signal::kill expects Option::None to properly handle EXIT
-            let sig: Option<Signal> = if sig_name.is_some_and(|name| name == "EXIT") {
-                None
-            } else {
-                let sig = (sig as i32)
-                    .try_into()
-                    .map_err(|e| std::io::Error::from_raw_os_error(e as i32))?;
-                Some(sig)
+                15 //SIGTERM
             };
 
             let pids = parse_pids(&pids_or_signals)?;
@@ -87,6 +94,9 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
                 ))
             } else {
                 kill(sig, &pids);
+                if let Some(timeout) = timeout {
+                    kill_after_timeout(timeout, &pids);
+                }
                 Ok(())
             }
         }
@@ -133,6 +143,16 @@ pub fn uu_app() -> Command {
                 .help("Sends given signal instead of SIGTERM")
                 .conflicts_with_all([options::LIST, options::TABLE]),
         )
+        .arg(
+            Arg::new(options::TIMEOUT)
+                .long(options::TIMEOUT)
+                .value_name("DURATION")
+                .help(
+                    "Waits DURATION for the processes to exit, \
+                     then sends SIGKILL to those still alive",
+                )
+                .conflicts_with_all([options::LIST, options::TABLE]),
+        )
         .arg(
             Arg::new(options::PIDS_OR_SIGNALS)
                 .hide(true)
@@ -140,7 +160,7 @@ pub fn uu_app() -> Command {
         )
 }
 
-fn handle_obsolete(args: &mut Vec<String>) -> Option<usize> {
+fn handle_obsolete(args: &mut Vec<String>) -> Option<i32> {
     // Sanity check
     if args.len() > 2 {
         // Old signal can only be in the first argument position
@@ -151,7 +171,7 @@ fn handle_obsolete(args: &mut Vec<String>) -> Option<usize> {
                 return None;
             }
             // Check if it is a valid signal
-            let opt_signal = signal_by_name_or_value(signal);
+            let opt_signal = signal_number(signal);
             if opt_signal.is_some() {
                 // remove the signal before return
                 args.remove(1);
@@ -169,27 +189,20 @@ fn table() {
 }
 
 fn print_signal(signal_name_or_value: &str) -> UResult<()> {
-    // Closure used to track the last 8 bits of the signal value
-    // when the -l option is passed only the lower 8 bits are important
-    // or the value is in range [128, 159]
-    // Example: kill -l 143 => TERM because 143 = 15 + 128
-    // Example: kill -l 2304 => EXIT
-    let lower_8_bits = |x: usize| x & 0xff;
-    let option_num_parse = signal_name_or_value.parse::<usize>().ok();
-
-    for (value, &signal) in ALL_SIGNALS.iter().enumerate() {
-        if signal.eq_ignore_ascii_case(signal_name_or_value)
-            || format!("SIG{signal}").eq_ignore_ascii_case(signal_name_or_value)
-        {
-            println!("{value}");
-            return Ok(());
-        } else if signal_name_or_value == value.to_string()
-            || option_num_parse.is_some_and(|signal_value| lower_8_bits(signal_value) == value)
-            || option_num_parse.is_some_and(|signal_value| signal_value == value + OFFSET)
-        {
-            println!("{signal}");
-            return Ok(());
+    if let Ok(value) = signal_name_or_value.parse::<i32>() {
+        // When the -l option is passed a value, only the lower 8 bits are
+        // important, or the value is in range [128, 159]
+        // Example: kill -l 143 => TERM because 143 = 15 + 128
+        // Example: kill -l 2304 => EXIT
+        for candidate in [value, value & 0xff, value - OFFSET] {
+            if let Some(name) = signal_name(candidate) {
+                println!("{name}");
+                return Ok(());
+            }
         }
+    } else if let Some(value) = signal_number(signal_name_or_value) {
+        println!("{value}");
+        return Ok(());
     }
     Err(USimpleError::new(
         1,
@@ -215,15 +228,9 @@ fn list(signals: &Vec<String>) {
     }
 }
 
-fn parse_signal_value(signal_name: &str) -> UResult<usize> {
-    let optional_signal_value = signal_by_name_or_value(signal_name);
-    match optional_signal_value {
-        Some(x) => Ok(x),
-        None => Err(USimpleError::new(
-            1,
-            format!("unknown signal name {}", signal_name.quote()),
-        )),
-    }
+fn parse_signal_value(signal_name: &str) -> UResult<i32> {
+    signal_number(signal_name)
+        .ok_or_else(|| USimpleError::new(1, format!("unknown signal name {}", signal_name.quote())))
 }
 
 fn parse_pids(pids: &[String]) -> UResult<Vec<i32>> {
@@ -236,10 +243,53 @@ fn parse_pids(pids: &[String]) -> UResult<Vec<i32>> {
         .collect()
 }
 
-fn kill(sig: Option<Signal>, pids: &[i32]) {
+/// Wait for the processes to exit, sending them SIGKILL if any is still
+/// alive when `timeout` expires.
+fn kill_after_timeout(timeout: Duration, pids: &[i32]) {
+    const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+    // The null signal only probes whether the process still exists
+    fn alive(pid: i32) -> bool {
+        if unsafe { libc::kill(pid, 0) } != 0 {
+            return false;
+        }
+        // A zombie is dead but stays visible until its parent reaps it
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        if let Ok(stat) = std::fs::read_to_string(format!("/proc/{pid}/stat")) {
+            // The state is the first field after the parenthesized command name
+            let state = stat.rsplit(')').next().unwrap_or("").trim_start();
+            if state.starts_with('Z') {
+                return false;
+            }
+        }
+        true
+    }
+
+    // `Duration::MAX` (from e.g. "--timeout=infinity") would overflow `Instant`
+    let deadline = Instant::now().checked_add(timeout);
+    loop {
+        if !pids.iter().any(|&pid| alive(pid)) {
+            return;
+        }
+        let interval = match deadline {
+            Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                Some(remaining) if !remaining.is_zero() => POLL_INTERVAL.min(remaining),
+                _ => break,
+            },
+            None => POLL_INTERVAL,
+        };
+        thread::sleep(interval);
+    }
+
+    let survivors: Vec<i32> = pids.iter().copied().filter(|&pid| alive(pid)).collect();
+    kill(libc::SIGKILL, &survivors);
+}
+
+fn kill(sig: i32, pids: &[i32]) {
     for &pid in pids {
-        if let Err(e) = signal::kill(Pid::from_raw(pid), sig) {
-            show!(Error::from_raw_os_error(e as i32)
+        // Signal 0 ("EXIT") only probes for the existence of the process
+        if unsafe { libc::kill(pid, sig) } != 0 {
+            show!(Error::last_os_error()
                 .map_err_context(|| format!("sending signal to {pid} failed")));
         }
     }
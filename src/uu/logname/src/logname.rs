@@ -7,22 +7,35 @@
 
 use clap::{crate_version, Command};
 use std::ffi::CStr;
+use uucore::error::set_exit_code;
 use uucore::{error::UResult, format_usage, help_about, help_usage, show_error};
 
 extern "C" {
-    // POSIX requires using getlogin (or equivalent code)
-    pub fn getlogin() -> *const libc::c_char;
+    // POSIX requires using getlogin (or equivalent code); getlogin_r is
+    // its thread-safe variant, not currently exposed by the libc crate.
+    fn getlogin_r(name: *mut libc::c_char, namesize: libc::size_t) -> libc::c_int;
 }
 
-fn get_userlogin() -> Option<String> {
-    unsafe {
-        let login: *const libc::c_char = getlogin();
-        if login.is_null() {
-            None
+/// Get the login name associated with the process via `getlogin_r(3)`.
+///
+/// On failure, returns the error number; `ENOENT` (or `ENXIO`/`ENOTTY`
+/// on some platforms) means that there is no login name at all.
+fn get_userlogin() -> Result<String, i32> {
+    // LOGIN_NAME_MAX is 256 on Linux; leave some headroom for other platforms.
+    let mut buf = [0u8; 512];
+    let err = unsafe { getlogin_r(buf.as_mut_ptr().cast(), buf.len()) };
+    if err != 0 {
+        // POSIX specifies the error number as the return value, but
+        // some platforms return -1 and set errno instead.
+        let err = if err < 0 {
+            std::io::Error::last_os_error().raw_os_error().unwrap_or(err)
         } else {
-            Some(String::from_utf8_lossy(CStr::from_ptr(login).to_bytes()).to_string())
-        }
+            err
+        };
+        return Err(err);
     }
+    let name = CStr::from_bytes_until_nul(&buf).map_err(|_| libc::EINVAL)?;
+    Ok(String::from_utf8_lossy(name.to_bytes()).to_string())
 }
 
 const ABOUT: &str = help_about!("logname.md");
@@ -33,8 +46,15 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     let _ = uu_app().try_get_matches_from(args)?;
 
     match get_userlogin() {
-        Some(userlogin) => println!("{userlogin}"),
-        None => show_error!("no login name"),
+        Ok(userlogin) => println!("{userlogin}"),
+        Err(libc::ENOENT | libc::ENXIO | libc::ENOTTY) => {
+            show_error!("no login name");
+            set_exit_code(1);
+        }
+        Err(err) => {
+            show_error!("{}", std::io::Error::from_raw_os_error(err));
+            set_exit_code(1);
+        }
     }
 
     Ok(())
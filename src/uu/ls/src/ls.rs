@@ -66,7 +66,7 @@ use uucore::{
     os_str_as_bytes_lossy,
     parse_size::parse_size_u64,
     shortcut_value_parser::ShortcutValueParser,
-    version_cmp::version_cmp,
+    version_cmp::VersionSortKey,
 };
 use uucore::{help_about, help_section, help_usage, parse_glob, show, show_error, show_warning};
 
@@ -689,6 +689,7 @@ fn match_quoting_style_name(style: &str, show_control: bool) -> Option<QuotingSt
         "escape" => Some(QuotingStyle::C {
             quotes: quoting_style::Quotes::None,
         }),
+        "locale" | "clocale" => Some(QuotingStyle::Locale),
         _ => None,
     }
 }
@@ -1405,6 +1406,8 @@ pub fn uu_app() -> Command {
                     PossibleValue::new("shell-escape-always"),
                     PossibleValue::new("c").alias("c-maybe"),
                     PossibleValue::new("escape"),
+                    PossibleValue::new("locale"),
+                    PossibleValue::new("clocale"),
                 ]))
                 .overrides_with_all([
                     options::QUOTING_STYLE,
@@ -1908,7 +1911,8 @@ pub fn uu_app() -> Command {
                 .long(options::GROUP_DIRECTORIES_FIRST)
                 .help(
                     "group directories before files; can be augmented with \
-                    a --sort option, but any use of --sort=none (-U) disables grouping",
+                    a --sort option; with --sort=none (-U) directories are \
+                    grouped but otherwise kept in directory order",
                 )
                 .action(ArgAction::SetTrue),
         )
@@ -2227,9 +2231,11 @@ fn sort_entries(entries: &mut [PathData], config: &Config, out: &mut BufWriter<S
         }
         // The default sort in GNU ls is case insensitive
         Sort::Name => entries.sort_by(|a, b| a.display_name.cmp(&b.display_name)),
-        Sort::Version => entries.sort_by(|a, b| {
-            version_cmp(&a.p_buf.to_string_lossy(), &b.p_buf.to_string_lossy())
-                .then(a.p_buf.to_string_lossy().cmp(&b.p_buf.to_string_lossy()))
+        // Pre-parse the names into sort keys, so each name is parsed once
+        // instead of on every comparison.
+        Sort::Version => entries.sort_by_cached_key(|k| {
+            let name = k.p_buf.to_string_lossy();
+            (VersionSortKey::new(&name), name.into_owned())
         }),
         Sort::Extension => entries.sort_by(|a, b| {
             a.p_buf
@@ -2250,7 +2256,9 @@ fn sort_entries(entries: &mut [PathData], config: &Config, out: &mut BufWriter<S
         entries.reverse();
     }
 
-    if config.group_directories_first && config.sort != Sort::None {
+    // The sort below is stable, so with `--sort=none` the directories keep
+    // their on-disk order while still being grouped before the other files.
+    if config.group_directories_first {
         entries.sort_by_key(|p| {
             let md = {
                 // We will always try to deref symlinks to group directories, so PathData.md
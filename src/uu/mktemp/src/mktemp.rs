@@ -430,7 +430,21 @@ fn dry_exec(tmpdir: &Path, prefix: &str, rand: usize, suffix: &str) -> UResult<P
     // Randomize.
     let bytes = &mut buf[prefix.len()..prefix.len() + rand];
     rand::thread_rng().fill(bytes);
-    for byte in bytes {
+
+    // Mix in a process-wide counter, so that repeated dry runs within
+    // one process never produce the same name without having to ask the
+    // OS for fresh entropy each time. XOR keeps the bytes fully random:
+    // the counter is zero in a fresh process and leaves them unchanged.
+    let mut counter = uucore::fast_inc::next();
+    for byte in bytes.iter_mut().rev() {
+        if counter == 0 {
+            break;
+        }
+        *byte ^= counter as u8;
+        counter >>= 8;
+    }
+
+    for byte in bytes.iter_mut() {
         *byte = match *byte % 62 {
             v @ 0..=9 => v + b'0',
             v @ 10..=35 => v - 10 + b'a',
@@ -21,7 +21,7 @@ use std::os::unix;
 use std::os::windows;
 use std::path::{absolute, Path, PathBuf};
 use uucore::backup_control::{self, source_is_target_backup};
-use uucore::display::Quotable;
+use uucore::display::{diag_quoted, Quotable};
 use uucore::error::{set_exit_code, FromIo, UResult, USimpleError, UUsageError};
 use uucore::fs::{
     are_hardlinks_or_one_way_symlink_to_same_file, are_hardlinks_to_same_file, canonicalize,
@@ -158,7 +158,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
 
     if let Some(ref maybe_dir) = target_dir {
         if !Path::new(&maybe_dir).is_dir() {
-            return Err(MvError::TargetNotADirectory(maybe_dir.quote().to_string()).into());
+            return Err(MvError::TargetNotADirectory(diag_quoted(maybe_dir).to_string()).into());
         }
     }
 
@@ -308,17 +308,17 @@ fn handle_two_paths(source: &Path, target: &Path, opts: &Options) -> UResult<()>
             io::ErrorKind::NotFound,
             format!(
                 "backing up {} might destroy source;  {} not moved",
-                target.quote(),
-                source.quote()
+                diag_quoted(target),
+                diag_quoted(source)
             ),
         )
         .into());
     }
     if source.symlink_metadata().is_err() {
         return Err(if path_ends_with_terminator(source) {
-            MvError::CannotStatNotADirectory(source.quote().to_string()).into()
+            MvError::CannotStatNotADirectory(diag_quoted(source).to_string()).into()
         } else {
-            MvError::NoSuchFile(source.quote().to_string()).into()
+            MvError::NoSuchFile(diag_quoted(source).to_string()).into()
         });
     }
 
@@ -330,7 +330,7 @@ fn handle_two_paths(source: &Path, target: &Path, opts: &Options) -> UResult<()>
         && !opts.no_target_dir
         && opts.update != UpdateMode::ReplaceIfOlder
     {
-        return Err(MvError::FailedToAccessNotADirectory(target.quote().to_string()).into());
+        return Err(MvError::FailedToAccessNotADirectory(diag_quoted(target).to_string()).into());
     }
 
     assert_not_same_file(source, target, target_is_dir, opts)?;
@@ -339,10 +339,10 @@ fn handle_two_paths(source: &Path, target: &Path, opts: &Options) -> UResult<()>
         if opts.no_target_dir {
             if source.is_dir() {
                 rename(source, target, opts, None).map_err_context(|| {
-                    format!("cannot move {} to {}", source.quote(), target.quote())
+                    format!("cannot move {} to {}", diag_quoted(source), diag_quoted(target))
                 })
             } else {
-                Err(MvError::DirectoryToNonDirectory(target.quote().to_string()).into())
+                Err(MvError::DirectoryToNonDirectory(diag_quoted(target).to_string()).into())
             }
         } else {
             move_files_into_dir(&[source.to_path_buf()], target, opts)
@@ -358,8 +358,8 @@ fn handle_two_paths(source: &Path, target: &Path, opts: &Options) -> UResult<()>
             OverwriteMode::Force => {}
         };
         Err(MvError::NonDirectoryToDirectory(
-            source.quote().to_string(),
-            target.quote().to_string(),
+            diag_quoted(source).to_string(),
+            diag_quoted(target).to_string(),
         )
         .into())
     } else {
@@ -426,9 +426,9 @@ fn assert_not_same_file(
             path.push('/');
             path.push_str(&file_name.to_string_lossy());
 
-            path.quote().to_string()
+            diag_quoted(&path).to_string()
         }
-        _ => target.quote().to_string(),
+        _ => diag_quoted(target).to_string(),
     };
 
     if same_file
@@ -437,13 +437,13 @@ fn assert_not_same_file(
             || source.ends_with("/.")
             || source.is_file())
     {
-        return Err(MvError::SameFile(source.quote().to_string(), target_display).into());
+        return Err(MvError::SameFile(diag_quoted(source).to_string(), target_display).into());
     } else if (same_file || canonicalized_target.starts_with(canonicalized_source))
         // don't error if we're moving a symlink of a directory into itself
         && !source.is_symlink()
     {
         return Err(
-            MvError::SelfTargetSubdirectory(source.quote().to_string(), target_display).into(),
+            MvError::SelfTargetSubdirectory(diag_quoted(source).to_string(), target_display).into(),
         );
     }
     Ok(())
@@ -453,7 +453,7 @@ fn handle_multiple_paths(paths: &[PathBuf], opts: &Options) -> UResult<()> {
     if opts.no_target_dir {
         return Err(UUsageError::new(
             1,
-            format!("mv: extra operand {}", paths[2].quote()),
+            format!("mv: extra operand {}", diag_quoted(&paths[2])),
         ));
     }
     let target_dir = paths.last().unwrap();
@@ -484,7 +484,7 @@ fn move_files_into_dir(files: &[PathBuf], target_dir: &Path, options: &Options)
     let mut moved_destinations: HashSet<PathBuf> = HashSet::with_capacity(files.len());
 
     if !target_dir.is_dir() {
-        return Err(MvError::NotADirectory(target_dir.quote().to_string()).into());
+        return Err(MvError::NotADirectory(diag_quoted(target_dir).to_string()).into());
     }
 
     let multi_progress = options.progress_bar.then(MultiProgress::new);
@@ -505,7 +505,7 @@ fn move_files_into_dir(files: &[PathBuf], target_dir: &Path, options: &Options)
 
     for sourcepath in files {
         if !sourcepath.exists() {
-            show!(MvError::NoSuchFile(sourcepath.quote().to_string()));
+            show!(MvError::NoSuchFile(diag_quoted(sourcepath).to_string()));
             continue;
         }
 
@@ -516,7 +516,7 @@ fn move_files_into_dir(files: &[PathBuf], target_dir: &Path, options: &Options)
         let targetpath = match sourcepath.file_name() {
             Some(name) => target_dir.join(name),
             None => {
-                show!(MvError::NoSuchFile(sourcepath.quote().to_string()));
+                show!(MvError::NoSuchFile(diag_quoted(sourcepath).to_string()));
                 continue;
             }
         };
@@ -548,8 +548,8 @@ fn move_files_into_dir(files: &[PathBuf], target_dir: &Path, options: &Options)
                 let e = e.map_err_context(|| {
                     format!(
                         "cannot move {} to {}",
-                        sourcepath.quote(),
-                        targetpath.quote()
+                        diag_quoted(sourcepath),
+                        diag_quoted(&targetpath)
                     )
                 });
                 match multi_progress {
@@ -590,7 +590,7 @@ fn rename(
         }
 
         if opts.update == UpdateMode::ReplaceNoneFail {
-            let err_msg = format!("not replacing {}", to.quote());
+            let err_msg = format!("not replacing {}", diag_quoted(to));
             return Err(io::Error::new(io::ErrorKind::Other, err_msg));
         }
 
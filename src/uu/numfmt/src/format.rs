@@ -3,6 +3,8 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 // spell-checker:ignore powf
+use std::env;
+
 use uucore::display::Quotable;
 
 use crate::options::{NumfmtOptions, RoundMethod, TransformOptions};
@@ -290,6 +292,54 @@ fn transform_to(
     })
 }
 
+// Returns the thousands separator of the current numeric locale, if it has
+// one. The standard library exposes no `localeconv()`, so consult a small
+// table of common locales, keyed on the language part of
+// `LC_ALL`/`LC_NUMERIC`/`LANG`. The default C locale has no separator.
+fn locale_grouping_separator() -> Option<char> {
+    let locale = ["LC_ALL", "LC_NUMERIC", "LANG"]
+        .iter()
+        .find_map(|var| env::var(var).ok().filter(|value| !value.is_empty()))
+        .unwrap_or_default();
+
+    match locale.split(['_', '.', '@']).next().unwrap_or("") {
+        "en" => Some(','),
+        "de" | "es" | "it" | "nl" | "pt" => Some('.'),
+        "cs" | "fi" | "fr" | "pl" | "ru" | "sv" => Some(' '),
+        _ => None,
+    }
+}
+
+// Inserts `separator` between each group of three digits in the integer part
+// of `number`. The sign and the fractional part, if any, are left untouched.
+fn group_digits(number: &str, separator: char) -> String {
+    let (integer, fraction) = match number.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (number, None),
+    };
+
+    let digits = integer.strip_prefix('-').unwrap_or(integer);
+    let mut grouped = String::with_capacity(number.len() + digits.len() / 3);
+
+    if digits.len() < integer.len() {
+        grouped.push('-');
+    }
+
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(c);
+    }
+
+    if let Some(fraction) = fraction {
+        grouped.push('.');
+        grouped.push_str(fraction);
+    }
+
+    grouped
+}
+
 fn format_string(
     source: &str,
     options: &NumfmtOptions,
@@ -316,6 +366,15 @@ fn format_string(
         precision,
     )?;
 
+    let number = if options.format.grouping {
+        match locale_grouping_separator() {
+            Some(separator) => group_digits(&number, separator),
+            None => number,
+        }
+    } else {
+        number
+    };
+
     // bring back the suffix before applying padding
     let number_with_suffix = match &options.suffix {
         Some(suffix) => format!("{number}{suffix}"),
@@ -445,6 +504,17 @@ mod tests {
         assert_eq!(0.12345, round_with_precision(0.12345, rm, 5));
     }
 
+    #[test]
+    fn test_group_digits() {
+        assert_eq!("0", group_digits("0", ','));
+        assert_eq!("100", group_digits("100", ','));
+        assert_eq!("1,000", group_digits("1000", ','));
+        assert_eq!("1,234,567", group_digits("1234567", ','));
+        assert_eq!("-1,234,567", group_digits("-1234567", ','));
+        assert_eq!("1,234.5678", group_digits("1234.5678", ','));
+        assert_eq!("1 234 567", group_digits("1234567", ' '));
+    }
+
     #[test]
     fn test_parse_implicit_precision() {
         assert_eq!(0, parse_implicit_precision(""));
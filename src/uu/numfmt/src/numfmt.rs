@@ -183,11 +183,18 @@ fn parse_options(args: &ArgMatches) -> Result<NumfmtOptions> {
         Range::from_list(fields)?
     };
 
-    let format = match args.get_one::<String>(options::FORMAT) {
+    let mut format: FormatOptions = match args.get_one::<String>(options::FORMAT) {
         Some(s) => s.parse()?,
         None => FormatOptions::default(),
     };
 
+    if args.get_flag(options::GROUPING) {
+        if args.contains_id(options::FORMAT) {
+            return Err("--grouping cannot be combined with --format".to_string());
+        }
+        format.grouping = true;
+    }
+
     if format.grouping && to != Unit::None {
         return Err("grouping cannot be combined with --to".to_string());
     }
@@ -298,6 +305,15 @@ pub fn uu_app() -> Command {
                 .value_name("N")
                 .default_value(options::FROM_UNIT_DEFAULT),
         )
+        .arg(
+            Arg::new(options::GROUPING)
+                .long(options::GROUPING)
+                .help(
+                    "group digits together (e.g. 1,000,000) according to \
+                     the current locale",
+                )
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             Arg::new(options::TO)
                 .long(options::TO)
@@ -5,7 +5,7 @@
 // spell-checker:ignore (ToDO) multifile curr fnames fname xfrd fillloop mockstream
 
 use std::fs::File;
-use std::io::{self, BufReader};
+use std::io::{self, BufReader, Read};
 
 use uucore::display::Quotable;
 use uucore::show_error;
@@ -21,6 +21,7 @@ pub enum InputSource<'a> {
 pub struct MultifileReader<'a> {
     ni: Vec<InputSource<'a>>,
     curr_file: Option<Box<dyn io::Read>>,
+    skip_bytes_per_file: u64,
     any_err: bool,
 }
 
@@ -29,10 +30,11 @@ pub trait HasError {
 }
 
 impl MultifileReader<'_> {
-    pub fn new(fnames: Vec<InputSource>) -> MultifileReader {
+    pub fn new(fnames: Vec<InputSource>, skip_bytes_per_file: u64) -> MultifileReader {
         let mut mf = MultifileReader {
             ni: fnames,
             curr_file: None, // normally this means done; call next_file()
+            skip_bytes_per_file,
             any_err: false,
         };
         mf.next_file();
@@ -73,6 +75,19 @@ impl MultifileReader<'_> {
                 }
             }
         }
+        // Skip a fixed-size header at the start of each input.
+        // A file shorter than the skip simply contributes no bytes.
+        if self.skip_bytes_per_file > 0 {
+            if let Some(ref mut curr_file) = self.curr_file {
+                if let Err(e) = io::copy(
+                    &mut curr_file.by_ref().take(self.skip_bytes_per_file),
+                    &mut io::sink(),
+                ) {
+                    show_error!("I/O: {}", e);
+                    self.any_err = true;
+                }
+            }
+        }
     }
 }
 
@@ -133,7 +148,7 @@ mod tests {
         ];
         let mut v = [0; 10];
 
-        let mut sut = MultifileReader::new(inputs);
+        let mut sut = MultifileReader::new(inputs, 0);
 
         assert_eq!(sut.read(v.as_mut()).unwrap(), 8);
         assert_eq!(v, [0x61, 0x62, 0x63, 0x64, 0x41, 0x42, 0x43, 0x44, 0, 0]);
@@ -148,7 +163,7 @@ mod tests {
         ];
         let mut v = [0; 5];
 
-        let mut sut = MultifileReader::new(inputs);
+        let mut sut = MultifileReader::new(inputs, 0);
 
         assert_eq!(sut.read(v.as_mut()).unwrap(), 5);
         assert_eq!(v, [0x61, 0x62, 0x63, 0x64, 0x41]);
@@ -156,6 +171,22 @@ mod tests {
         assert_eq!(v, [0x42, 0x43, 0x44, 0x64, 0x41]); // last two bytes are not overwritten
     }
 
+    #[test]
+    fn test_multi_file_reader_skip_bytes_per_file() {
+        let inputs = vec![
+            InputSource::Stream(Box::new(Cursor::new(&b"xxabcd"[..]))),
+            InputSource::Stream(Box::new(Cursor::new(&b"xxABCD"[..]))),
+            InputSource::Stream(Box::new(Cursor::new(&b"x"[..]))), // shorter than the skip
+        ];
+        let mut v = [0; 10];
+
+        let mut sut = MultifileReader::new(inputs, 2);
+
+        assert_eq!(sut.read(v.as_mut()).unwrap(), 8);
+        assert_eq!(v, [0x61, 0x62, 0x63, 0x64, 0x41, 0x42, 0x43, 0x44, 0, 0]);
+        assert_eq!(sut.read(v.as_mut()).unwrap(), 0);
+    }
+
     #[test]
     fn test_multi_file_reader_read_error() {
         let c = Cursor::new(&b"1234"[..])
@@ -167,7 +198,7 @@ mod tests {
         ];
         let mut v = [0; 5];
 
-        let mut sut = MultifileReader::new(inputs);
+        let mut sut = MultifileReader::new(inputs, 0);
 
         assert_eq!(sut.read(v.as_mut()).unwrap(), 5);
         assert_eq!(v, [49, 50, 51, 52, 65]);
@@ -200,7 +231,7 @@ mod tests {
         ];
         let mut v = [0; 5];
 
-        let mut sut = MultifileReader::new(inputs);
+        let mut sut = MultifileReader::new(inputs, 0);
 
         assert_eq!(sut.read(v.as_mut()).unwrap(), 5);
         assert_eq!(v, [0x61, 0x62, 0x63, 0x64, 0x41]);
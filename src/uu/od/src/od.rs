@@ -57,6 +57,7 @@ pub(crate) mod options {
     pub const HELP: &str = "help";
     pub const ADDRESS_RADIX: &str = "address-radix";
     pub const SKIP_BYTES: &str = "skip-bytes";
+    pub const SKIP_BYTES_PER_FILE: &str = "skip-bytes-per-file";
     pub const READ_BYTES: &str = "read-bytes";
     pub const ENDIAN: &str = "endian";
     pub const STRINGS: &str = "strings";
@@ -70,6 +71,7 @@ pub(crate) mod options {
 struct OdOptions {
     byte_order: ByteOrder,
     skip_bytes: u64,
+    skip_bytes_per_file: u64,
     read_bytes: Option<u64>,
     label: Option<u64>,
     input_strings: Vec<String>,
@@ -109,6 +111,19 @@ impl OdOptions {
             },
         };
 
+        let skip_bytes_per_file = match matches.get_one::<String>(options::SKIP_BYTES_PER_FILE) {
+            None => 0,
+            Some(s) => match parse_number_of_bytes(s) {
+                Ok(n) => n,
+                Err(e) => {
+                    return Err(USimpleError::new(
+                        1,
+                        format_error_message(&e, s, options::SKIP_BYTES_PER_FILE),
+                    ))
+                }
+            },
+        };
+
         let mut label: Option<u64> = None;
 
         let parsed_input = parse_inputs(matches)
@@ -202,6 +217,7 @@ impl OdOptions {
         Ok(Self {
             byte_order,
             skip_bytes,
+            skip_bytes_per_file,
             read_bytes,
             label,
             input_strings,
@@ -231,6 +247,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     let mut input = open_input_peek_reader(
         &od_options.input_strings,
         od_options.skip_bytes,
+        od_options.skip_bytes_per_file,
         od_options.read_bytes,
     );
     let mut input_decoder = InputDecoder::new(
@@ -280,6 +297,12 @@ pub fn uu_app() -> Command {
                 .help("Skip bytes input bytes before formatting and writing.")
                 .value_name("BYTES"),
         )
+        .arg(
+            Arg::new(options::SKIP_BYTES_PER_FILE)
+                .long(options::SKIP_BYTES_PER_FILE)
+                .help("Skip bytes input bytes at the start of each input file.")
+                .value_name("BYTES"),
+        )
         .arg(
             Arg::new(options::READ_BYTES)
                 .short('N')
@@ -598,11 +621,13 @@ fn print_bytes(prefix: &str, input_decoder: &MemoryDecoder, output_info: &Output
 
 /// returns a reader implementing `PeekRead + Read + HasError` providing the combined input
 ///
-/// `skip_bytes` is the number of bytes skipped from the input
+/// `skip_bytes` is the number of bytes skipped from the combined input
+/// `skip_bytes_per_file` is the number of bytes skipped at the start of each file
 /// `read_bytes` is an optional limit to the number of bytes to read
 fn open_input_peek_reader(
     input_strings: &[String],
     skip_bytes: u64,
+    skip_bytes_per_file: u64,
     read_bytes: Option<u64>,
 ) -> PeekReader<PartialReader<MultifileReader>> {
     // should return  "impl PeekRead + Read + HasError" when supported in (stable) rust
@@ -614,7 +639,7 @@ fn open_input_peek_reader(
         })
         .collect::<Vec<_>>();
 
-    let mf = MultifileReader::new(inputs);
+    let mf = MultifileReader::new(inputs, skip_bytes_per_file);
     let pr = PartialReader::new(mf, skip_bytes, read_bytes);
     PeekReader::new(pr)
 }
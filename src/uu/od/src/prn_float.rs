@@ -66,9 +66,20 @@ fn format_float(f: f64, width: usize, precision: usize) -> String {
         if f == -0.0 && f.is_sign_negative() {
             return format!("{:>width$}", "-0");
         }
-        if f == 0.0 || !f.is_finite() {
+        if f == 0.0 {
             return format!("{f:width$}");
         }
+        if !f.is_finite() {
+            // Always use the lowercase spellings of GNU od, not
+            // whatever std::fmt produces for the platform.
+            let s = match (f.is_nan(), f.is_sign_negative()) {
+                (true, false) => "nan",
+                (true, true) => "-nan",
+                (false, false) => "inf",
+                (false, true) => "-inf",
+            };
+            return format!("{s:>width$}");
+        }
         return format!("{f:width$e}"); // subnormal numbers
     }
 
@@ -160,7 +171,8 @@ fn test_format_flo32() {
     assert_eq!(format_flo32(1e-45), "         1e-45");
 
     assert_eq!(format_flo32(-3.402_823_466e+38), " -3.4028235e38");
-    assert_eq!(format_flo32(f32::NAN), "           NaN");
+    assert_eq!(format_flo32(f32::NAN), "           nan");
+    assert_eq!(format_flo32(-f32::NAN), "          -nan");
     assert_eq!(format_flo32(f32::INFINITY), "           inf");
     assert_eq!(format_flo32(f32::NEG_INFINITY), "          -inf");
     assert_eq!(format_flo32(-0.0), "            -0");
@@ -193,7 +205,8 @@ fn test_format_flo64() {
         "-2.2250738585072014e-308"
     );
     assert_eq!(format_flo64(4e-320), "                  4e-320");
-    assert_eq!(format_flo64(f64::NAN), "                     NaN");
+    assert_eq!(format_flo64(f64::NAN), "                     nan");
+    assert_eq!(format_flo64(-f64::NAN), "                    -nan");
     assert_eq!(format_flo64(f64::INFINITY), "                     inf");
     assert_eq!(format_flo64(f64::NEG_INFINITY), "                    -inf");
     assert_eq!(format_flo64(-0.0), "                      -0");
@@ -219,7 +232,7 @@ fn test_format_flo16() {
 
     assert_eq!(format_flo16(f16::MIN_POSITIVE_SUBNORMAL), " 5.960e-8");
     assert_eq!(format_flo16(f16::MIN), " -6.550e4");
-    assert_eq!(format_flo16(f16::NAN), "      NaN");
+    assert_eq!(format_flo16(f16::NAN), "      nan");
     assert_eq!(format_flo16(f16::INFINITY), "      inf");
     assert_eq!(format_flo16(f16::NEG_INFINITY), "     -inf");
     assert_eq!(format_flo16(f16::NEG_ZERO), "       -0");
@@ -2,15 +2,13 @@
 //
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
-#![allow(unused_must_use)] // because we of writeln!
-
 // spell-checker:ignore (ToDO) lstat
 use clap::{crate_version, Arg, ArgAction, Command};
 use std::fs;
-use std::io::{ErrorKind, Write};
+use std::io::ErrorKind;
 use uucore::display::Quotable;
 use uucore::error::{set_exit_code, UResult, UUsageError};
-use uucore::{format_usage, help_about, help_usage};
+use uucore::{format_usage, help_about, help_usage, show_error};
 
 // operating mode
 enum Mode {
@@ -125,21 +123,19 @@ fn check_basic(path: &[String]) -> bool {
     let total_len = joined_path.len();
     // path length
     if total_len > POSIX_PATH_MAX {
-        writeln!(
-            std::io::stderr(),
+        show_error!(
             "limit {POSIX_PATH_MAX} exceeded by length {total_len} of file name {joined_path}"
         );
         return false;
     } else if total_len == 0 {
-        writeln!(std::io::stderr(), "empty file name");
+        show_error!("empty file name");
         return false;
     }
     // components: character portability and length
     for p in path {
         let component_len = p.len();
         if component_len > POSIX_NAME_MAX {
-            writeln!(
-                std::io::stderr(),
+            show_error!(
                 "limit {} exceeded by length {} of file name component {}",
                 POSIX_NAME_MAX,
                 component_len,
@@ -160,17 +156,13 @@ fn check_extra(path: &[String]) -> bool {
     // components: leading hyphens
     for p in path {
         if p.starts_with('-') {
-            writeln!(
-                std::io::stderr(),
-                "leading hyphen in file name component {}",
-                p.quote()
-            );
+            show_error!("leading hyphen in file name component {}", p.quote());
             return false;
         }
     }
     // path length
     if path.join("/").is_empty() {
-        writeln!(std::io::stderr(), "empty file name");
+        show_error!("empty file name");
         return false;
     }
     true
@@ -182,8 +174,7 @@ fn check_default(path: &[String]) -> bool {
     let total_len = joined_path.len();
     // path length
     if total_len > libc::PATH_MAX as usize {
-        writeln!(
-            std::io::stderr(),
+        show_error!(
             "limit {} exceeded by length {} of file name {}",
             libc::PATH_MAX,
             total_len,
@@ -197,7 +188,7 @@ fn check_default(path: &[String]) -> bool {
         // but some non-POSIX hosts do (as an alias for "."),
         // so allow "" if `symlink_metadata` (corresponds to `lstat`) does.
         if fs::symlink_metadata(&joined_path).is_err() {
-            writeln!(std::io::stderr(), "pathchk: '': No such file or directory",);
+            show_error!("'': No such file or directory");
             return false;
         }
     }
@@ -206,8 +197,7 @@ fn check_default(path: &[String]) -> bool {
     for p in path {
         let component_len = p.len();
         if component_len > libc::FILENAME_MAX as usize {
-            writeln!(
-                std::io::stderr(),
+            show_error!(
                 "limit {} exceeded by length {} of file name component {}",
                 libc::FILENAME_MAX,
                 component_len,
@@ -229,7 +219,7 @@ fn check_searchable(path: &str) -> bool {
             if e.kind() == ErrorKind::NotFound {
                 true
             } else {
-                writeln!(std::io::stderr(), "{e}");
+                show_error!("{e}");
                 false
             }
         }
@@ -242,8 +232,7 @@ fn check_portable_chars(path_segment: &str) -> bool {
     for (i, ch) in path_segment.as_bytes().iter().enumerate() {
         if !VALID_CHARS.contains(ch) {
             let invalid = path_segment[i..].chars().next().unwrap();
-            writeln!(
-                std::io::stderr(),
+            show_error!(
                 "nonportable character '{}' in file name component {}",
                 invalid,
                 path_segment.quote()
@@ -41,6 +41,7 @@ mod options {
     pub const HEADER: &str = "header";
     pub const DOUBLE_SPACE: &str = "double-space";
     pub const NUMBER_LINES: &str = "number-lines";
+    pub const NUMBER_SEPARATOR: &str = "number-separator";
     pub const FIRST_LINE_NUMBER: &str = "first-line-number";
     pub const PAGES: &str = "pages";
     pub const OMIT_HEADER: &str = "omit-header";
@@ -215,6 +216,15 @@ pub fn uu_app() -> Command {
                 .allow_hyphen_values(true)
                 .value_name("[char][width]"),
         )
+        .arg(
+            Arg::new(options::NUMBER_SEPARATOR)
+                .long(options::NUMBER_SEPARATOR)
+                .help(
+                    "Use CHAR instead of <tab> between the line number and the text; \
+                only effective together with -n.",
+                )
+                .value_name("CHAR"),
+        )
         .arg(
             Arg::new(options::FIRST_LINE_NUMBER)
                 .short('N')
@@ -551,6 +561,21 @@ fn build_options(
             }
         });
 
+    let number = if let Some(separator) = matches.get_one::<String>(options::NUMBER_SEPARATOR) {
+        if separator.len() != 1 {
+            return Err(PrError::EncounteredErrors(format!(
+                "invalid --number-separator argument {}: must be a single byte",
+                separator.quote()
+            )));
+        }
+        number.map(|mode| NumberingMode {
+            separator: separator.clone(),
+            ..mode
+        })
+    } else {
+        number
+    };
+
     let double_space = matches.get_flag(options::DOUBLE_SPACE);
 
     let content_line_separator = if double_space {
@@ -14,7 +14,7 @@ use std::ops::BitOr;
 use std::os::unix::ffi::OsStrExt;
 use std::path::MAIN_SEPARATOR;
 use std::path::{Path, PathBuf};
-use uucore::display::Quotable;
+use uucore::display::{diag_quoted, Quotable};
 use uucore::error::{UResult, USimpleError, UUsageError};
 use uucore::{
     format_usage, help_about, help_section, help_usage, os_str_as_bytes, prompt_yes, show_error,
@@ -316,7 +316,7 @@ pub fn remove(files: &[&OsStr], options: &Options) -> bool {
                 } else {
                     show_error!(
                         "cannot remove {}: No such file or directory",
-                        filename.quote()
+                        diag_quoted(filename)
                     );
                     true
                 }
@@ -355,9 +355,9 @@ fn handle_dir(path: &Path, options: &Options) -> bool {
                         // GNU compatibility (rm/fail-eacces.sh)
                         // here, GNU doesn't use some kind of remove_dir_all
                         // It will show directory+file
-                        show_error!("cannot remove {}: {}", path.quote(), "Permission denied");
+                        show_error!("cannot remove {}: {}", diag_quoted(path), "Permission denied");
                     } else {
-                        show_error!("cannot remove {}: {}", path.quote(), e);
+                        show_error!("cannot remove {}: {}", diag_quoted(path), e);
                     }
                 }
             }
@@ -399,7 +399,7 @@ fn handle_dir(path: &Path, options: &Options) -> bool {
                     }
                     Err(e) => {
                         had_err = true;
-                        show_error!("recursing in {}: {}", path.quote(), e);
+                        show_error!("recursing in {}: {}", diag_quoted(path), e);
                     }
                 }
             }
@@ -420,7 +420,7 @@ fn handle_dir(path: &Path, options: &Options) -> bool {
     } else {
         show_error!(
             "cannot remove {}: Is a directory", // GNU's rm error message does not include help
-            path.quote()
+            diag_quoted(path)
         );
         had_err = true;
     }
@@ -444,28 +444,28 @@ fn remove_dir(path: &Path, options: &Options) -> bool {
                                 // GNU compatibility (rm/fail-eacces.sh)
                                 show_error!(
                                     "cannot remove {}: {}",
-                                    path.quote(),
+                                    diag_quoted(path),
                                     "Permission denied"
                                 );
                             } else {
-                                show_error!("cannot remove {}: {}", path.quote(), e);
+                                show_error!("cannot remove {}: {}", diag_quoted(path), e);
                             }
                             return true;
                         }
                     }
                 } else {
                     // directory can be read but is not empty
-                    show_error!("cannot remove {}: Directory not empty", path.quote());
+                    show_error!("cannot remove {}: Directory not empty", diag_quoted(path));
                     return true;
                 }
             } else {
                 // called to remove a symlink_dir (windows) without "-r"/"-R" or "-d"
-                show_error!("cannot remove {}: Is a directory", path.quote());
+                show_error!("cannot remove {}: Is a directory", diag_quoted(path));
                 return true;
             }
         } else {
             // GNU's rm shows this message if directory is empty but not readable
-            show_error!("cannot remove {}: Directory not empty", path.quote());
+            show_error!("cannot remove {}: Directory not empty", diag_quoted(path));
             return true;
         }
     }
@@ -484,9 +484,9 @@ fn remove_file(path: &Path, options: &Options) -> bool {
             Err(e) => {
                 if e.kind() == std::io::ErrorKind::PermissionDenied {
                     // GNU compatibility (rm/fail-eacces.sh)
-                    show_error!("cannot remove {}: {}", path.quote(), "Permission denied");
+                    show_error!("cannot remove {}: {}", diag_quoted(path), "Permission denied");
                 } else {
-                    show_error!("cannot remove {}: {}", path.quote(), e);
+                    show_error!("cannot remove {}: {}", diag_quoted(path), e);
                 }
                 return true;
             }
@@ -28,6 +28,17 @@ pub enum SeqError {
     /// No arguments were passed to this function, 1 or more is required
     #[error("missing operand")]
     NoArguments,
+
+    /// A `--char-range` endpoint was not a single printable ASCII character.
+    ///
+    /// The parameter is the offending argument as read from the command
+    /// line.
+    #[error("invalid character range argument: {}", .0.quote())]
+    InvalidCharRangeArgument(String),
+
+    /// `--char-range` was given fewer or more than two endpoints.
+    #[error("--char-range requires exactly two arguments")]
+    CharRangeArgumentCount,
 }
 
 fn parse_error_type(e: &ParseNumberError) -> &'static str {
@@ -18,10 +18,7 @@ mod extendedbigdecimal;
 mod hexadecimalfloat;
 
 // public to allow fuzzing
-#[cfg(fuzzing)]
 pub mod number;
-#[cfg(not(fuzzing))]
-mod number;
 mod numberparse;
 use crate::error::SeqError;
 use crate::extendedbigdecimal::ExtendedBigDecimal;
@@ -30,6 +27,7 @@ use crate::number::PreciseNumber;
 const ABOUT: &str = help_about!("seq.md");
 const USAGE: &str = help_usage!("seq.md");
 
+const OPT_CHAR_RANGE: &str = "char-range";
 const OPT_SEPARATOR: &str = "separator";
 const OPT_TERMINATOR: &str = "terminator";
 const OPT_EQUAL_WIDTH: &str = "equal-width";
@@ -112,6 +110,19 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         format: matches.get_one::<String>(OPT_FORMAT).map(|s| s.as_str()),
     };
 
+    if matches.get_flag(OPT_CHAR_RANGE) {
+        let (first, last) = match numbers.as_slice() {
+            [first, last] => (parse_char_endpoint(first)?, parse_char_endpoint(last)?),
+            _ => return Err(SeqError::CharRangeArgumentCount.into()),
+        };
+        let result = print_char_seq(first, last, &options.separator, &options.terminator);
+        return match result {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == ErrorKind::BrokenPipe => Ok(()),
+            Err(err) => Err(err.map_err_context(|| "write error".into())),
+        };
+    }
+
     let (first, first_precision) = if numbers.len() > 1 {
         match numbers[0].parse() {
             Ok(num) => (num, hexadecimalfloat::parse_precision(numbers[0])),
@@ -196,6 +207,12 @@ pub fn uu_app() -> Command {
                 .help("Equalize widths of all numbers by padding with zeros")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new(OPT_CHAR_RANGE)
+                .long(OPT_CHAR_RANGE)
+                .help("Generate a sequence of printable ASCII characters instead of numbers")
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             Arg::new(OPT_FORMAT)
                 .short('f')
@@ -212,6 +229,42 @@ pub fn uu_app() -> Command {
         )
 }
 
+/// Parse a `--char-range` endpoint, which must be a single printable
+/// ASCII character.
+fn parse_char_endpoint(s: &str) -> Result<char, SeqError> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_graphic() || c == ' ' => Ok(c),
+        _ => Err(SeqError::InvalidCharRangeArgument(s.to_string())),
+    }
+}
+
+/// Character based code path for `--char-range`.
+///
+/// Prints the characters from `first` to `last` inclusive; nothing is
+/// printed if `first` sorts after `last`.
+fn print_char_seq(
+    first: char,
+    last: char,
+    separator: &str,
+    terminator: &str,
+) -> std::io::Result<()> {
+    let stdout = stdout();
+    let mut stdout = stdout.lock();
+    let mut is_first_iteration = true;
+    for c in first..=last {
+        if !is_first_iteration {
+            write!(stdout, "{separator}")?;
+        }
+        write!(stdout, "{c}")?;
+        is_first_iteration = false;
+    }
+    if !is_first_iteration {
+        write!(stdout, "{terminator}")?;
+    }
+    stdout.flush()
+}
+
 fn done_printing<T: Zero + PartialOrd>(next: &T, increment: &T, last: &T) -> bool {
     if increment >= &T::zero() {
         next > last
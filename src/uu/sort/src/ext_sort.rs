@@ -13,6 +13,7 @@ use std::cmp::Ordering;
 use std::fs::File;
 use std::io::Write;
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 use std::{
     io::Read,
     sync::mpsc::{Receiver, SyncSender},
@@ -33,7 +34,7 @@ use crate::{
     chunks::{self, Chunk},
     compare_by, merge, sort_by, GlobalSettings,
 };
-use crate::{print_sorted, Line};
+use crate::{benchmark_report, print_sorted, Line};
 
 const START_BUFFER_SIZE: usize = 8_000;
 
@@ -98,12 +99,16 @@ fn reader_writer<
     )?;
     match read_result {
         ReadResult::WroteChunksToFile { tmp_files } => {
+            let start = settings.benchmark.then(Instant::now);
             merge::merge_with_file_limit::<_, _, Tmp>(
                 tmp_files.into_iter().map(|c| c.reopen()),
                 settings,
                 output,
                 tmp_dir,
             )?;
+            if let Some(start) = start {
+                benchmark_report("merging", start.elapsed());
+            }
         }
         ReadResult::SortedSingleChunk(chunk) => {
             if settings.unique {
@@ -152,9 +157,13 @@ fn reader_writer<
 /// The function that is executed on the sorter thread.
 fn sorter(receiver: &Receiver<Chunk>, sender: &SyncSender<Chunk>, settings: &GlobalSettings) {
     while let Ok(mut payload) = receiver.recv() {
+        let start = settings.benchmark.then(Instant::now);
         payload.with_dependent_mut(|_, contents| {
             sort_by(&mut contents.lines, settings, &contents.line_data);
         });
+        if let Some(start) = start {
+            benchmark_report("sorting chunk", start.elapsed());
+        }
         if sender.send(payload).is_err() {
             // The receiver has gone away, likely because the other thread hit an error.
             // We stop silently because the actual error is printed by the other thread.
@@ -187,8 +196,10 @@ fn read_write_loop<I: WriteableTmpFile>(
     let mut file = files.next().unwrap()?;
 
     let mut carry_over = vec![];
+    let mut read_time = Duration::ZERO;
     // kick things off with two reads
     for _ in 0..2 {
+        let start = settings.benchmark.then(Instant::now);
         let should_continue = chunks::read(
             &sender,
             RecycledChunk::new(if START_BUFFER_SIZE < buffer_size {
@@ -203,8 +214,14 @@ fn read_write_loop<I: WriteableTmpFile>(
             separator,
             settings,
         )?;
+        if let Some(start) = start {
+            read_time += start.elapsed();
+        }
 
         if !should_continue {
+            if settings.benchmark {
+                benchmark_report("reading input", read_time);
+            }
             drop(sender);
             // We have already read the whole input. Since we are in our first two reads,
             // this means that we can fit the whole input into memory. Bypass writing below and
@@ -239,6 +256,7 @@ fn read_write_loop<I: WriteableTmpFile>(
         let recycled_chunk = chunk.recycle();
 
         if let Some(sender) = &sender_option {
+            let start = settings.benchmark.then(Instant::now);
             let should_continue = chunks::read(
                 sender,
                 recycled_chunk,
@@ -249,7 +267,13 @@ fn read_write_loop<I: WriteableTmpFile>(
                 separator,
                 settings,
             )?;
+            if let Some(start) = start {
+                read_time += start.elapsed();
+            }
             if !should_continue {
+                if settings.benchmark {
+                    benchmark_report("reading input", read_time);
+                }
                 sender_option = None;
             }
         }
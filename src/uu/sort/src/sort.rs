@@ -33,18 +33,20 @@ use std::env;
 use std::ffi::{OsStr, OsString};
 use std::fs::{File, OpenOptions};
 use std::hash::{Hash, Hasher};
-use std::io::{stdin, stdout, BufRead, BufReader, BufWriter, Read, Write};
+use std::io::{stdin, stdout, BufReader, BufWriter, Read, Write};
 use std::num::IntErrorKind;
 use std::ops::Range;
 use std::path::Path;
 use std::path::PathBuf;
 use std::str::Utf8Error;
+use std::time::Instant;
 use thiserror::Error;
 use unicode_width::UnicodeWidthStr;
 use uucore::display::Quotable;
 use uucore::error::strip_errno;
 use uucore::error::{set_exit_code, UError, UResult, USimpleError, UUsageError};
 use uucore::line_ending::LineEnding;
+use uucore::lines::ByteLines;
 use uucore::parse_size::{ParseSizeError, Parser};
 use uucore::shortcut_value_parser::ShortcutValueParser;
 use uucore::version_cmp::version_cmp;
@@ -90,6 +92,7 @@ mod options {
     pub const DICTIONARY_ORDER: &str = "dictionary-order";
     pub const MERGE: &str = "merge";
     pub const DEBUG: &str = "debug";
+    pub const BENCHMARK: &str = "benchmark";
     pub const IGNORE_CASE: &str = "ignore-case";
     pub const IGNORE_LEADING_BLANKS: &str = "ignore-leading-blanks";
     pub const IGNORE_NONPRINTING: &str = "ignore-nonprinting";
@@ -248,6 +251,7 @@ impl Output {
 pub struct GlobalSettings {
     mode: SortMode,
     debug: bool,
+    benchmark: bool,
     ignore_leading_blanks: bool,
     ignore_case: bool,
     dictionary_order: bool,
@@ -325,6 +329,7 @@ impl Default for GlobalSettings {
         Self {
             mode: SortMode::Default,
             debug: false,
+            benchmark: false,
             ignore_leading_blanks: false,
             ignore_case: false,
             dictionary_order: false,
@@ -1028,6 +1033,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     };
 
     settings.debug = matches.get_flag(options::DEBUG);
+    settings.benchmark = matches.get_flag(options::BENCHMARK);
 
     // check whether user specified a zero terminated list of files for input, otherwise read files from args
     let mut files: Vec<OsString> = if matches.contains_id(options::FILES0_FROM) {
@@ -1040,7 +1046,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         for path in &files0_from {
             let reader = open(path)?;
             let buf_reader = BufReader::new(reader);
-            for line in buf_reader.split(b'\0').flatten() {
+            for line in ByteLines::zero_terminated(buf_reader).flatten() {
                 files.push(OsString::from(
                     std::str::from_utf8(&line)
                         .expect("Could not parse string from zero terminated input."),
@@ -1512,6 +1518,13 @@ pub fn uu_app() -> Command {
                 .help("underline the parts of the line that are actually used for sorting")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new(options::BENCHMARK)
+                .long(options::BENCHMARK)
+                .help("print the time spent in each phase to stderr")
+                .action(ArgAction::SetTrue)
+                .hide(true),
+        )
         .arg(
             Arg::new(options::FILES)
                 .action(ArgAction::Append)
@@ -1527,7 +1540,12 @@ fn exec(
     tmp_dir: &mut TmpDirWrapper,
 ) -> UResult<()> {
     if settings.merge {
-        merge::merge(files, settings, output, tmp_dir)
+        let start = settings.benchmark.then(Instant::now);
+        let result = merge::merge(files, settings, output, tmp_dir);
+        if let Some(start) = start {
+            benchmark_report("merging", start.elapsed());
+        }
+        result
     } else if settings.check {
         if files.len() > 1 {
             Err(UUsageError::new(2, "only one file allowed with -c"))
@@ -1818,15 +1836,24 @@ fn month_compare(a: &str, b: &str) -> Ordering {
     }
 }
 
+/// Report how long one phase of the sort took on stderr, for `--benchmark`.
+fn benchmark_report(phase: &str, duration: std::time::Duration) {
+    eprintln!("[sort] {phase}: {:.3} s", duration.as_secs_f64());
+}
+
 fn print_sorted<'a, T: Iterator<Item = &'a Line<'a>>>(
     iter: T,
     settings: &GlobalSettings,
     output: Output,
 ) {
+    let start = settings.benchmark.then(Instant::now);
     let mut writer = output.into_write();
     for line in iter {
         line.print(&mut writer, settings);
     }
+    if let Some(start) = start {
+        benchmark_report("writing output", start.elapsed());
+    }
 }
 
 fn open(path: impl AsRef<OsStr>) -> UResult<Box<dyn Read + Send>> {
@@ -179,6 +179,11 @@ fn eval(stack: &mut Vec<Symbol>) -> ParseResult<bool> {
 /// `a` is the left hand side
 /// `b` is the left hand side
 /// `op` the operation (ex: -eq, -lt, etc)
+///
+/// As specified by POSIX and matching GNU test, the arguments are
+/// always read as decimal: `010` is ten, not octal eight, and hex
+/// literals like `0x10` are rejected as invalid integers (bash's
+/// builtin `test` accepts them, GNU test does not).
 fn integers(a: &OsStr, b: &OsStr, op: &OsStr) -> ParseResult<bool> {
     // Parse the two inputs
     let a: i128 = a
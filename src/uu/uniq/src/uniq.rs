@@ -13,6 +13,7 @@ use std::io::{stdin, stdout, BufRead, BufReader, BufWriter, Write};
 use std::num::IntErrorKind;
 use uucore::display::Quotable;
 use uucore::error::{FromIo, UError, UResult, USimpleError};
+use uucore::lines::ByteLines;
 use uucore::posix::{posix_version, OBSOLETE};
 use uucore::shortcut_value_parser::ShortcutValueParser;
 use uucore::{format_usage, help_about, help_section, help_usage};
@@ -71,7 +72,7 @@ impl Uniq {
         let mut first_line_printed = false;
         let mut group_count = 1;
         let line_terminator = self.get_line_terminator();
-        let mut lines = reader.split(line_terminator);
+        let mut lines = ByteLines::with_delimiter(reader, line_terminator);
         let mut line = match lines.next() {
             Some(l) => l?,
             None => return Ok(()),
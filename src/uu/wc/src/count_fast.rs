@@ -77,6 +77,14 @@ fn count_bytes_using_splice(fd: &impl AsFd) -> Result<usize, usize> {
     Ok(byte_count)
 }
 
+/// Count the number of Unicode grapheme clusters (user-perceived characters)
+/// in `buf`. Each invalid byte sequence counts as a single replacement
+/// character, just like a character that cannot be decoded.
+pub(crate) fn count_graphemes(buf: &[u8]) -> usize {
+    use unicode_segmentation::UnicodeSegmentation;
+    String::from_utf8_lossy(buf).graphemes(true).count()
+}
+
 /// In the special case where we only need to count the number of bytes. There
 /// are several optimizations we can do:
 ///   1. On Unix,  we can simply `stat` the file if it is regular.
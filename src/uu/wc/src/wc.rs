@@ -34,7 +34,7 @@ use uucore::{
 };
 
 use crate::{
-    count_fast::{count_bytes_chars_and_lines_fast, count_bytes_fast},
+    count_fast::{count_bytes_chars_and_lines_fast, count_bytes_fast, count_graphemes},
     countable::WordCountable,
     word_count::WordCount,
 };
@@ -45,9 +45,11 @@ const MINIMUM_WIDTH: usize = 7;
 struct Settings<'a> {
     show_bytes: bool,
     show_chars: bool,
+    show_graphemes: bool,
     show_lines: bool,
     show_words: bool,
     show_max_line_length: bool,
+    show_max_line_bytes: bool,
     files0_from: Option<Input<'a>>,
     total_when: TotalWhen,
 }
@@ -58,9 +60,11 @@ impl Default for Settings<'_> {
         Self {
             show_bytes: true,
             show_chars: false,
+            show_graphemes: false,
             show_lines: true,
             show_words: true,
             show_max_line_length: false,
+            show_max_line_bytes: false,
             files0_from: None,
             total_when: TotalWhen::default(),
         }
@@ -81,9 +85,11 @@ impl<'a> Settings<'a> {
         let settings = Self {
             show_bytes: matches.get_flag(options::BYTES),
             show_chars: matches.get_flag(options::CHAR),
+            show_graphemes: matches.get_flag(options::GRAPHEMES),
             show_lines: matches.get_flag(options::LINES),
             show_words: matches.get_flag(options::WORDS),
             show_max_line_length: matches.get_flag(options::MAX_LINE_LENGTH),
+            show_max_line_bytes: matches.get_flag(options::BYTES_PER_LINE),
             files0_from,
             total_when,
         };
@@ -103,8 +109,10 @@ impl<'a> Settings<'a> {
         [
             self.show_bytes,
             self.show_chars,
+            self.show_graphemes,
             self.show_lines,
             self.show_max_line_length,
+            self.show_max_line_bytes,
             self.show_words,
         ]
         .into_iter()
@@ -118,8 +126,10 @@ const USAGE: &str = help_usage!("wc.md");
 
 mod options {
     pub static BYTES: &str = "bytes";
+    pub static BYTES_PER_LINE: &str = "bytes-per-line";
     pub static CHAR: &str = "chars";
     pub static FILES0_FROM: &str = "files0-from";
+    pub static GRAPHEMES: &str = "graphemes";
     pub static LINES: &str = "lines";
     pub static MAX_LINE_LENGTH: &str = "max-line-length";
     pub static TOTAL: &str = "total";
@@ -408,6 +418,13 @@ pub fn uu_app() -> Command {
                 .help("print the byte counts")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new(options::BYTES_PER_LINE)
+                .short('B')
+                .long(options::BYTES_PER_LINE)
+                .help("print the length of the longest line in bytes")
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             Arg::new(options::CHAR)
                 .short('m')
@@ -427,6 +444,12 @@ pub fn uu_app() -> Command {
                 .value_parser(ValueParser::os_string())
                 .value_hint(clap::ValueHint::FilePath),
         )
+        .arg(
+            Arg::new(options::GRAPHEMES)
+                .long(options::GRAPHEMES)
+                .help("print the grapheme cluster counts")
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             Arg::new(options::LINES)
                 .short('l')
@@ -473,6 +496,13 @@ fn word_count_from_reader<T: WordCountable>(
     mut reader: T,
     settings: &Settings,
 ) -> (WordCount, Option<io::Error>) {
+    if settings.show_graphemes || settings.show_max_line_bytes {
+        // These counts are not covered by the specialized fast paths below;
+        // grapheme segmentation in particular needs to see the complete
+        // decoded stream.
+        return word_count_from_reader_exhaustive(reader);
+    }
+
     match (
         settings.show_bytes,
         settings.show_chars,
@@ -664,6 +694,61 @@ fn word_count_from_reader_specialized<
     (total, None)
 }
 
+/// Like [`word_count_from_reader_specialized`], but computes every count,
+/// including grapheme clusters and byte line lengths. A grapheme cluster may
+/// span the boundary between two buffered chunks, so the last (possibly still
+/// incomplete) cluster of each chunk is carried over and segmented together
+/// with the following chunk.
+fn word_count_from_reader_exhaustive<T: WordCountable>(
+    reader: T,
+) -> (WordCount, Option<io::Error>) {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let mut total = WordCount::default();
+    let mut reader = BufReadDecoder::new(reader.buffered());
+    let mut in_word = false;
+    let mut current_len = 0;
+    let mut current_line_bytes = 0;
+    let mut carry = String::new();
+    while let Some(chunk) = reader.next_strict() {
+        match chunk {
+            Ok(text) => {
+                process_chunk::<true, true, true, true>(
+                    &mut total,
+                    text,
+                    &mut current_len,
+                    &mut in_word,
+                );
+                for ch in text.chars() {
+                    match ch {
+                        '\n' | '\r' | '\x0c' => {
+                            total.max_line_bytes = max(current_line_bytes, total.max_line_bytes);
+                            current_line_bytes = 0;
+                        }
+                        _ => current_line_bytes += ch.len_utf8(),
+                    }
+                }
+                carry.push_str(text);
+                if let Some((idx, _)) = carry.grapheme_indices(true).next_back() {
+                    total.graphemes += count_graphemes(&carry.as_bytes()[..idx]);
+                    carry.drain(..idx);
+                }
+            }
+            Err(e) => {
+                if let Some(e) = handle_error(e, &mut total) {
+                    return (total, Some(e));
+                }
+            }
+        }
+    }
+    if !carry.is_empty() {
+        total.graphemes += 1;
+    }
+    total.max_line_bytes = max(current_line_bytes, total.max_line_bytes);
+
+    (total, None)
+}
+
 enum CountResult {
     /// Nothing went wrong.
     Success(WordCount),
@@ -889,8 +974,10 @@ fn print_stats(
         (settings.show_lines, result.lines),
         (settings.show_words, result.words),
         (settings.show_chars, result.chars),
+        (settings.show_graphemes, result.graphemes),
         (settings.show_bytes, result.bytes),
         (settings.show_max_line_length, result.max_line_length),
+        (settings.show_max_line_bytes, result.max_line_bytes),
     ];
 
     let mut space = "";
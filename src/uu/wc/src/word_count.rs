@@ -9,9 +9,11 @@ use std::ops::{Add, AddAssign};
 pub struct WordCount {
     pub bytes: usize,
     pub chars: usize,
+    pub graphemes: usize,
     pub lines: usize,
     pub words: usize,
     pub max_line_length: usize,
+    pub max_line_bytes: usize,
 }
 
 impl Add for WordCount {
@@ -21,9 +23,11 @@ impl Add for WordCount {
         Self {
             bytes: self.bytes + other.bytes,
             chars: self.chars + other.chars,
+            graphemes: self.graphemes + other.graphemes,
             lines: self.lines + other.lines,
             words: self.words + other.words,
             max_line_length: max(self.max_line_length, other.max_line_length),
+            max_line_bytes: max(self.max_line_bytes, other.max_line_bytes),
         }
     }
 }
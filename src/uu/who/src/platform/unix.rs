@@ -223,7 +223,7 @@ impl Who {
 
             for ut in records {
                 if !self.my_line_only || cur_tty == ut.tty_device() {
-                    if self.need_users && ut.is_user_process() {
+                    if self.need_users && ut.is_user_process() && ut.is_login_session() {
                         self.print_user(&ut)?;
                     } else if self.need_runlevel && run_level_chk(ut.record_type()) {
                         if cfg!(target_os = "linux") {
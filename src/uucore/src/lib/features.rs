@@ -16,6 +16,8 @@ pub mod colors;
 pub mod custom_tz_fmt;
 #[cfg(feature = "encoding")]
 pub mod encoding;
+#[cfg(feature = "fast-inc")]
+pub mod fast_inc;
 #[cfg(feature = "format")]
 pub mod format;
 #[cfg(feature = "fs")]
@@ -49,10 +51,14 @@ pub mod entries;
 pub mod perms;
 #[cfg(all(unix, any(feature = "pipes", feature = "buf-copy")))]
 pub mod pipes;
+#[cfg(all(target_os = "linux", feature = "proc"))]
+pub mod proc;
 #[cfg(all(target_os = "linux", feature = "proc-info"))]
 pub mod proc_info;
 #[cfg(all(unix, feature = "process"))]
 pub mod process;
+#[cfg(feature = "progress")]
+pub mod progress;
 #[cfg(all(target_os = "linux", feature = "tty"))]
 pub mod tty;
 
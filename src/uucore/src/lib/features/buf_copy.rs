@@ -90,6 +90,31 @@ mod tests {
         assert_eq!(buf, data);
     }
 
+    #[test]
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn test_copy_stream_pipe_to_pipe() {
+        let (mut src_read, mut src_write) = pipes::pipe().unwrap();
+        let (mut dest_read, mut dest_write) = pipes::pipe().unwrap();
+        let data = b"Hello, world!";
+
+        let writer = thread::spawn(move || {
+            src_write.write_all(data).unwrap();
+        });
+        let reader = thread::spawn(move || {
+            let mut buf = Vec::new();
+            dest_read.read_to_end(&mut buf).unwrap();
+            buf
+        });
+
+        let result = copy_stream(&mut src_read, &mut dest_write).unwrap();
+        drop(dest_write);
+        writer.join().unwrap();
+        let buf = reader.join().unwrap();
+
+        assert_eq!(result, data.len() as u64);
+        assert_eq!(buf, data);
+    }
+
     #[test]
     #[cfg(not(unix))]
     // Test for non-unix platforms. We use regular files instead.
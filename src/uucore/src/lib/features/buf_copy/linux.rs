@@ -59,6 +59,20 @@ where
     R: Read + AsFd + AsRawFd,
     S: Write + AsFd + AsRawFd,
 {
+    // If both ends are pipes, splice() can move the data directly from
+    // one to the other, without even the intermediate pipe that
+    // splice_write() sets up.
+    if is_pipe(src).unwrap_or(false) && is_pipe(dest).unwrap_or(false) {
+        let result = splice_pipe_to_pipe(src, &dest.as_fd())?;
+        if !result.1 {
+            return Ok(result.0);
+        }
+
+        let result = std::io::copy(src, dest)?;
+        dest.flush()?;
+        return Ok(result);
+    }
+
     // If we're on Linux or Android, try to use the splice() system call
     // for faster writing. If it works, we're done.
     let result = splice_write(src, &dest.as_fd())?;
@@ -78,6 +92,35 @@ where
     Ok(result)
 }
 
+/// Check whether `fd` refers to a pipe (FIFO).
+fn is_pipe(fd: &impl AsRawFd) -> nix::Result<bool> {
+    use nix::sys::stat::{fstat, SFlag};
+
+    let stat = fstat(fd.as_raw_fd())?;
+    Ok(SFlag::from_bits_truncate(stat.st_mode) & SFlag::S_IFMT == SFlag::S_IFIFO)
+}
+
+/// Move data from the pipe `source` into the pipe `dest` with direct `splice`
+/// calls, without an intermediate pipe or user-space buffer.
+///
+/// Returns the number of bytes moved and whether the caller has to fall back
+/// to a user-space copy for the rest of the stream.
+fn splice_pipe_to_pipe<R, S>(source: &R, dest: &S) -> UResult<(u64, bool)>
+where
+    R: AsFd + AsRawFd,
+    S: AsFd + AsRawFd,
+{
+    let mut bytes: u64 = 0;
+
+    loop {
+        match splice(&source, &dest, SPLICE_SIZE) {
+            Ok(0) => return Ok((bytes, false)),
+            Ok(n) => bytes += n as u64,
+            Err(_) => return Ok((bytes, true)),
+        }
+    }
+}
+
 /// Write from source `handle` into destination `write_fd` using Linux-specific
 /// `splice` system call.
 ///
@@ -10,9 +10,10 @@ use os_display::Quotable;
 use regex::bytes::{Match, Regex};
 use std::{
     borrow::Cow,
+    collections::HashSet,
     ffi::OsStr,
     fmt::Display,
-    fs::File,
+    fs::{self, File},
     io::{self, stdin, BufReader, Read, Write},
     path::Path,
     str,
@@ -20,10 +21,12 @@ use std::{
 
 use crate::{
     error::{FromIo, UError, UResult, USimpleError},
-    os_str_as_bytes, os_str_from_bytes, read_os_string_lines, show, show_error, show_warning_caps,
+    os_str_as_bytes, os_str_from_bytes, read_os_string_lines, show, show_error, show_warning,
+    show_warning_caps,
     sum::{
         Blake2b, Blake3, Digest, DigestWriter, Md5, Sha1, Sha224, Sha256, Sha384, Sha3_224,
-        Sha3_256, Sha3_384, Sha3_512, Sha512, Shake128, Shake256, Sm3, BSD, CRC, CRC32B, SYSV,
+        Sha3_256, Sha3_384, Sha3_512, Sha512, Shake128, Shake256, Sm3, Xxh3, BSD, CRC, CRC32B,
+        SYSV,
     },
     util_name,
 };
@@ -44,10 +47,11 @@ pub const ALGORITHM_OPTIONS_SHA512: &str = "sha512";
 pub const ALGORITHM_OPTIONS_BLAKE2B: &str = "blake2b";
 pub const ALGORITHM_OPTIONS_BLAKE3: &str = "blake3";
 pub const ALGORITHM_OPTIONS_SM3: &str = "sm3";
+pub const ALGORITHM_OPTIONS_XXH3: &str = "xxh3";
 pub const ALGORITHM_OPTIONS_SHAKE128: &str = "shake128";
 pub const ALGORITHM_OPTIONS_SHAKE256: &str = "shake256";
 
-pub const SUPPORTED_ALGORITHMS: [&str; 16] = [
+pub const SUPPORTED_ALGORITHMS: [&str; 17] = [
     ALGORITHM_OPTIONS_SYSV,
     ALGORITHM_OPTIONS_BSD,
     ALGORITHM_OPTIONS_CRC,
@@ -62,13 +66,15 @@ pub const SUPPORTED_ALGORITHMS: [&str; 16] = [
     ALGORITHM_OPTIONS_BLAKE2B,
     ALGORITHM_OPTIONS_BLAKE3,
     ALGORITHM_OPTIONS_SM3,
+    ALGORITHM_OPTIONS_XXH3,
     ALGORITHM_OPTIONS_SHAKE128,
     ALGORITHM_OPTIONS_SHAKE256,
 ];
 
 pub struct HashAlgorithm {
     pub name: &'static str,
-    pub create_fn: Box<dyn Fn() -> Box<dyn Digest + 'static>>,
+    // `Send + Sync` so that utilities can create digests on worker threads.
+    pub create_fn: Box<dyn Fn() -> Box<dyn Digest + 'static> + Send + Sync>,
     pub bits: usize,
 }
 
@@ -199,6 +205,9 @@ impl Default for ChecksumVerbose {
 pub struct ChecksumOptions {
     pub binary: bool,
     pub ignore_missing: bool,
+    /// Print a warning for each file in the checksum file's directory
+    /// that the checksum file does not list.
+    pub warn_missing: bool,
     pub strict: bool,
     pub verbose: ChecksumVerbose,
 }
@@ -441,6 +450,11 @@ pub fn detect_algo(algo: &str, length: Option<usize>) -> UResult<HashAlgorithm>
             create_fn: Box::new(|| Box::new(Sm3::new())),
             bits: 512,
         }),
+        ALGORITHM_OPTIONS_XXH3 => Ok(HashAlgorithm {
+            name: ALGORITHM_OPTIONS_XXH3,
+            create_fn: Box::new(|| Box::new(Xxh3::new())),
+            bits: 128,
+        }),
         ALGORITHM_OPTIONS_SHAKE128 | "shake128sum" => {
             let bits =
                 length.ok_or_else(|| USimpleError::new(1, "--bits required for SHAKE128"))?;
@@ -786,7 +800,15 @@ fn process_algo_based_line(
     let expected_checksum = get_expected_digest_as_hex_string(line_info, digest_char_length_hint)
         .ok_or(LineCheckError::ImproperlyFormatted)?;
 
-    let algo = detect_algo(&algo_name, algo_byte_len)?;
+    // `detect_algo` expects the length in bytes for BLAKE2b, but in bits for
+    // the SHA3 family (as given with --bits on the command line).
+    let algo_len = if algo_name.starts_with("sha3") {
+        algo_byte_len.map(|len| len * 8)
+    } else {
+        algo_byte_len
+    };
+
+    let algo = detect_algo(&algo_name, algo_len)?;
 
     compute_and_check_digest_from_file(filename_to_check, &expected_checksum, algo, opts)
 }
@@ -866,6 +888,46 @@ fn process_checksum_line(
     }
 }
 
+/// Print a warning for each file in the checksum file's directory that
+/// the checksum file does not list.
+///
+/// Unlisted files can be a sign of tampering or drift: they were added
+/// after the checksum file was produced, so verification says nothing
+/// about them.
+fn warn_unlisted_files(checksum_file: &OsStr, input_is_stdin: bool, listed: &HashSet<Vec<u8>>) {
+    let dir = match Path::new(checksum_file).parent() {
+        Some(parent) if !input_is_stdin && !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    let mut files: Vec<_> = entries
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_file()))
+        .map(|entry| entry.path())
+        .collect();
+    files.sort();
+
+    for path in files {
+        // Compare the path as it would appear in the checksum file:
+        // without the leading "./" when the directory is implicit.
+        let candidate = path.strip_prefix(".").unwrap_or(&path).as_os_str();
+        if candidate == checksum_file {
+            continue;
+        }
+        if os_str_as_bytes(candidate).is_ok_and(|bytes| !listed.contains(bytes)) {
+            show_warning!(
+                "{}: not listed in {}",
+                candidate.maybe_quote(),
+                checksum_file.maybe_quote()
+            );
+        }
+    }
+}
+
 fn process_checksum_file(
     filename_input: &OsStr,
     cli_algo_name: Option<&str>,
@@ -900,8 +962,16 @@ fn process_checksum_file(
     // message for the current line if improperly formatted.
     // Behavior tested in gnu_cksum_c::test_warn
     let mut last_algo = None;
+    // The file names covered by the checksum file, for `--warn-missing`.
+    let mut listed_files: HashSet<Vec<u8>> = HashSet::new();
 
     for (i, line) in lines.iter().enumerate() {
+        if opts.warn_missing {
+            if let Some(line_info) = LineInfo::parse(line, &mut cached_regex) {
+                listed_files.insert(line_info.filename);
+            }
+        }
+
         let line_result = process_checksum_line(
             line,
             i,
@@ -965,6 +1035,10 @@ fn process_checksum_file(
         print_cksum_report(&res);
     }
 
+    if opts.warn_missing {
+        warn_unlisted_files(filename_input, input_is_stdin, &listed_files);
+    }
+
     if opts.ignore_missing && res.correct == 0 {
         // we have only bad format
         // and we had ignore-missing
@@ -0,0 +1,49 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! A process-wide, thread-safe, monotonically increasing counter.
+//!
+//! Utilities that make up unique names (e.g. the random part of a
+//! `mktemp` template) can mix these values into a name to rule out
+//! collisions within one process, without asking the OS for fresh
+//! entropy on every call.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Return the next value of the process-wide counter.
+///
+/// Every call returns a value that no other call in this process has
+/// returned before. Only uniqueness is guaranteed; the order in which
+/// concurrent threads observe values is unspecified.
+pub fn next() -> u64 {
+    COUNTER.fetch_add(1, Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::next;
+    use std::collections::HashSet;
+    use std::thread;
+
+    #[test]
+    fn test_unique_across_threads() {
+        const THREADS: usize = 8;
+        const PER_THREAD: usize = 1250;
+
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| thread::spawn(|| (0..PER_THREAD).map(|_| next()).collect::<Vec<_>>()))
+            .collect();
+
+        let mut seen = HashSet::new();
+        for handle in handles {
+            for value in handle.join().unwrap() {
+                assert!(seen.insert(value), "duplicate counter value {value}");
+            }
+        }
+        assert_eq!(seen.len(), THREADS * PER_THREAD);
+    }
+}
@@ -153,10 +153,9 @@ impl<C: FormatChar> FormatItem<C> {
         args: &mut impl Iterator<Item = &'a FormatArgument>,
     ) -> Result<ControlFlow<()>, FormatError> {
         match self {
-            Self::Spec(spec) => spec.write(writer, args)?,
-            Self::Char(c) => return c.write(writer).map_err(FormatError::IoError),
-        };
-        Ok(ControlFlow::Continue(()))
+            Self::Spec(spec) => spec.write(writer, args),
+            Self::Char(c) => c.write(writer).map_err(FormatError::IoError),
+        }
     }
 }
 
@@ -233,6 +232,24 @@ pub fn parse_escape_only(fmt: &[u8]) -> impl Iterator<Item = EscapedChar> + '_ {
     })
 }
 
+/// Expand the backslash escape sequences in the argument to a `%b` directive.
+///
+/// Handles the POSIX `\0ooo` octal escapes and the named escapes, as well as
+/// the GNU extensions `\xHH` (hex) and `\uNNNN`/`\UNNNNNNNN` (Unicode).
+/// Returns the expanded bytes together with [`ControlFlow::Break`] if a `\c`
+/// sequence was encountered, which must stop all further output of the
+/// `printf` invocation, not just the current argument.
+pub fn parse_printf_escapes(input: &[u8]) -> (Vec<u8>, ControlFlow<()>) {
+    let mut parsed = Vec::new();
+    for c in parse_escape_only(input) {
+        match c.write(&mut parsed).expect("writing to a Vec cannot fail") {
+            ControlFlow::Continue(()) => {}
+            ControlFlow::Break(()) => return (parsed, ControlFlow::Break(())),
+        }
+    }
+    (parsed, ControlFlow::Continue(()))
+}
+
 /// Write a formatted string to stdout.
 ///
 /// `format_string` contains the template and `args` contains the
@@ -262,7 +279,9 @@ fn printf_writer<'a>(
 ) -> Result<(), FormatError> {
     let mut args = args.into_iter();
     for item in parse_spec_only(format_string.as_ref()) {
-        item?.write(&mut writer, &mut args)?;
+        if item?.write(&mut writer, &mut args)?.is_break() {
+            break;
+        }
     }
     Ok(())
 }
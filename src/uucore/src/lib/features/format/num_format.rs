@@ -407,19 +407,21 @@ fn format_float_shortest(
     // Retrieve the exponent. Note that log10 is undefined for negative numbers.
     // To avoid NaN or zero (due to i32 conversion), use the absolute value of f.
     let mut exponent = f.abs().log10().floor() as i32;
-    if f != 0.0 && exponent < -4 || exponent > precision as i32 {
-        // Scientific-ish notation (with a few differences)
-        let mut normalized = f / 10.0_f64.powi(exponent);
+    let mut normalized = f / 10.0_f64.powi(exponent);
 
-        // If the normalized value will be rounded to a value greater than 10
-        // we need to correct.
-        if (normalized * 10_f64.powi(precision as i32)).round() / 10_f64.powi(precision as i32)
-            >= 10.0
-        {
-            normalized /= 10.0;
-            exponent += 1;
-        }
+    // Rounding to the requested number of significant digits may push the
+    // value to the next power of ten, e.g. 999999.5 -> 1000000. POSIX bases
+    // the choice between the scientific and decimal styles on the exponent
+    // after rounding, so correct it first.
+    if (normalized.abs() * 10_f64.powi(precision as i32)).round() / 10_f64.powi(precision as i32)
+        >= 10.0
+    {
+        normalized /= 10.0;
+        exponent += 1;
+    }
 
+    if exponent < -4 || exponent > precision as i32 {
+        // Scientific-ish notation (with a few differences)
         let additional_dot = if precision == 0 && ForceDecimal::Yes == force_decimal {
             "."
         } else {
@@ -654,6 +656,20 @@ mod test {
         assert_eq!(f(99_999_999.0), "1.e+08");
     }
 
+    #[test]
+    fn shortest_float_switch_on_exponent_after_rounding() {
+        use super::format_float_shortest;
+        // glibc decides between the decimal and scientific styles based on
+        // the exponent after rounding to the significant digits.
+        let f = |x| format_float_shortest(x, 6, Case::Lowercase, ForceDecimal::No);
+        assert_eq!(f(999_999.4), "999999");
+        assert_eq!(f(999_999.5), "1e+06");
+        assert_eq!(f(-999_999.5), "-1e+06");
+        assert_eq!(f(0.000_099_999_94), "9.99999e-05");
+        assert_eq!(f(0.000_099_999_999), "0.0001");
+        assert_eq!(f(-0.000_099_999_999), "-0.0001");
+    }
+
     #[test]
     fn strip_insignificant_end() {
         use super::strip_fractional_zeroes_and_dot;
@@ -12,7 +12,7 @@ use super::{
         self, Case, FloatVariant, ForceDecimal, Formatter, NumberAlignment, PositiveSign, Prefix,
         UnsignedIntVariant,
     },
-    parse_escape_only, ArgumentIter, FormatChar, FormatError,
+    parse_printf_escapes, ArgumentIter, FormatError,
 };
 use std::{io::Write, ops::ControlFlow};
 
@@ -311,18 +311,20 @@ impl Spec {
         &self,
         mut writer: impl Write,
         mut args: impl ArgumentIter<'a>,
-    ) -> Result<(), FormatError> {
+    ) -> Result<ControlFlow<()>, FormatError> {
         match self {
             Self::Char { width, align_left } => {
-                let width = resolve_asterisk(*width, &mut args)?.unwrap_or(0);
-                write_padded(writer, &[args.get_char()], width, *align_left)
+                let (width, neg_width) =
+                    resolve_asterisk_width(*width, &mut args)?.unwrap_or_default();
+                write_padded(writer, &[args.get_char()], width, *align_left || neg_width)
             }
             Self::String {
                 width,
                 align_left,
                 precision,
             } => {
-                let width = resolve_asterisk(*width, &mut args)?.unwrap_or(0);
+                let (width, neg_width) =
+                    resolve_asterisk_width(*width, &mut args)?.unwrap_or_default();
 
                 // GNU does do this truncation on a byte level, see for instance:
                 //     printf "%.1s" 🙃
@@ -330,27 +332,19 @@ impl Spec {
                 // For now, we let printf panic when we truncate within a code point.
                 // TODO: We need to not use Rust's formatting for aligning the output,
                 // so that we can just write bytes to stdout without panicking.
-                let precision = resolve_asterisk(*precision, &mut args)?;
+                let precision = resolve_asterisk_precision(*precision, &mut args)?;
                 let s = args.get_str();
                 let truncated = match precision {
                     Some(p) if p < s.len() => &s[..p],
                     _ => s,
                 };
-                write_padded(writer, truncated.as_bytes(), width, *align_left)
+                write_padded(writer, truncated.as_bytes(), width, *align_left || neg_width)
             }
             Self::EscapedString => {
-                let s = args.get_str();
-                let mut parsed = Vec::new();
-                for c in parse_escape_only(s.as_bytes()) {
-                    match c.write(&mut parsed)? {
-                        ControlFlow::Continue(()) => {}
-                        ControlFlow::Break(()) => {
-                            // TODO: This should break the _entire execution_ of printf
-                            break;
-                        }
-                    };
-                }
-                writer.write_all(&parsed).map_err(FormatError::IoError)
+                let (parsed, stop) = parse_printf_escapes(args.get_str().as_bytes());
+                writer.write_all(&parsed).map_err(FormatError::IoError)?;
+                // `\c` stops the entire execution of printf, not just this argument.
+                return Ok(stop);
             }
             Self::QuotedString => {
                 let s = escape_name(
@@ -374,8 +368,9 @@ impl Spec {
                 positive_sign,
                 alignment,
             } => {
-                let width = resolve_asterisk(*width, &mut args)?.unwrap_or(0);
-                let precision = resolve_asterisk(*precision, &mut args)?.unwrap_or(0);
+                let (width, neg_width) =
+                    resolve_asterisk_width(*width, &mut args)?.unwrap_or_default();
+                let precision = resolve_asterisk_precision(*precision, &mut args)?.unwrap_or(0);
                 let i = args.get_i64();
 
                 if precision as u64 > i32::MAX as u64 {
@@ -386,7 +381,11 @@ impl Spec {
                     width,
                     precision,
                     positive_sign: *positive_sign,
-                    alignment: *alignment,
+                    alignment: if neg_width {
+                        NumberAlignment::Left
+                    } else {
+                        *alignment
+                    },
                 }
                 .fmt(writer, i)
                 .map_err(FormatError::IoError)
@@ -397,8 +396,9 @@ impl Spec {
                 precision,
                 alignment,
             } => {
-                let width = resolve_asterisk(*width, &mut args)?.unwrap_or(0);
-                let precision = resolve_asterisk(*precision, &mut args)?.unwrap_or(0);
+                let (width, neg_width) =
+                    resolve_asterisk_width(*width, &mut args)?.unwrap_or_default();
+                let precision = resolve_asterisk_precision(*precision, &mut args)?.unwrap_or(0);
                 let i = args.get_u64();
 
                 if precision as u64 > i32::MAX as u64 {
@@ -409,7 +409,11 @@ impl Spec {
                     variant: *variant,
                     precision,
                     width,
-                    alignment: *alignment,
+                    alignment: if neg_width {
+                        NumberAlignment::Left
+                    } else {
+                        *alignment
+                    },
                 }
                 .fmt(writer, i)
                 .map_err(FormatError::IoError)
@@ -423,8 +427,9 @@ impl Spec {
                 alignment,
                 precision,
             } => {
-                let width = resolve_asterisk(*width, &mut args)?.unwrap_or(0);
-                let precision = resolve_asterisk(*precision, &mut args)?.unwrap_or(6);
+                let (width, neg_width) =
+                    resolve_asterisk_width(*width, &mut args)?.unwrap_or_default();
+                let precision = resolve_asterisk_precision(*precision, &mut args)?.unwrap_or(6);
                 let f = args.get_f64();
 
                 if precision as u64 > i32::MAX as u64 {
@@ -438,22 +443,56 @@ impl Spec {
                     case: *case,
                     force_decimal: *force_decimal,
                     positive_sign: *positive_sign,
-                    alignment: *alignment,
+                    alignment: if neg_width {
+                        NumberAlignment::Left
+                    } else {
+                        *alignment
+                    },
                 }
                 .fmt(writer, f)
                 .map_err(FormatError::IoError)
             }
         }
+        .map(|()| ControlFlow::Continue(()))
     }
 }
 
-fn resolve_asterisk<'a>(
+/// Determines the width of a format field, reading the next argument if the
+/// width was given as `*`.
+///
+/// Returns the width together with a flag that is `true` when the argument
+/// was negative, which POSIX specifies to mean left alignment (as if the `-`
+/// flag had been given).
+fn resolve_asterisk_width<'a>(
+    option: Option<CanAsterisk<usize>>,
+    mut args: impl ArgumentIter<'a>,
+) -> Result<Option<(usize, bool)>, FormatError> {
+    Ok(match option {
+        None => None,
+        Some(CanAsterisk::Asterisk) => {
+            let w = args.get_i64();
+            if w < 0 {
+                Some((usize::try_from(w.unsigned_abs()).unwrap_or(0), true))
+            } else {
+                Some((usize::try_from(w).unwrap_or(0), false))
+            }
+        }
+        Some(CanAsterisk::Fixed(w)) => Some((w, false)),
+    })
+}
+
+/// Determines the precision of a format field, reading the next argument if
+/// the precision was given as `*`.
+///
+/// A negative precision argument is taken as if the precision were omitted,
+/// as specified by POSIX.
+fn resolve_asterisk_precision<'a>(
     option: Option<CanAsterisk<usize>>,
     mut args: impl ArgumentIter<'a>,
 ) -> Result<Option<usize>, FormatError> {
     Ok(match option {
         None => None,
-        Some(CanAsterisk::Asterisk) => Some(usize::try_from(args.get_u64()).ok().unwrap_or(0)),
+        Some(CanAsterisk::Asterisk) => usize::try_from(args.get_i64()).ok(),
         Some(CanAsterisk::Fixed(w)) => Some(w),
     })
 }
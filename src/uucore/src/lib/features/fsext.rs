@@ -643,6 +643,7 @@ pub trait FsMeta {
     fn free_file_nodes(&self) -> u64;
     fn fsid(&self) -> u64;
     fn namelen(&self) -> u64;
+    fn filesystem_type_name(&self) -> &'static str;
 }
 
 #[cfg(unix)]
@@ -851,6 +852,142 @@ impl FsMeta for StatFs {
     fn namelen(&self) -> u64 {
         self.f_namemax as u64 // spell-checker:disable-line
     }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn filesystem_type_name(&self) -> &'static str {
+        fs_magic_name(self.fs_type())
+    }
+    #[cfg(any(
+        target_vendor = "apple",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
+    fn filesystem_type_name(&self) -> &'static str {
+        // The BSDs report the name directly; keep only names we recognize
+        // so that the return type can stay `'static`.
+        // spell-checker:disable
+        let name = unsafe { CStr::from_ptr(self.f_fstypename.as_ptr()) };
+        match name.to_bytes() {
+            b"apfs" => "apfs",
+            b"autofs" => "autofs",
+            b"cd9660" => "cd9660",
+            b"devfs" => "devfs",
+            b"exfat" => "exfat",
+            b"fdescfs" => "fdescfs",
+            b"ffs" => "ffs",
+            b"hfs" => "hfs",
+            b"msdos" | b"msdosfs" => "msdos",
+            b"nfs" => "nfs",
+            b"ntfs" => "ntfs",
+            b"nullfs" => "nullfs",
+            b"procfs" => "procfs",
+            b"smbfs" => "smbfs",
+            b"tmpfs" => "tmpfs",
+            b"ufs" => "ufs",
+            b"unionfs" => "unionfs",
+            b"zfs" => "zfs",
+            _ => "unknown",
+        }
+        // spell-checker:enable
+    }
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "android",
+        target_vendor = "apple",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    )))]
+    fn filesystem_type_name(&self) -> &'static str {
+        // statvfs carries neither a magic number nor a type name.
+        "unknown"
+    }
+}
+
+/// Filesystem magic numbers from `linux/magic.h`, as reported in the
+/// `f_type` field of `statfs(2)`.
+// spell-checker:disable
+pub mod fs_magic {
+    pub const BINFMTFS_MAGIC: i64 = 0x4249_4E4D;
+    pub const BPF_FS_MAGIC: i64 = 0xCAFE_4A11;
+    pub const BTRFS_SUPER_MAGIC: i64 = 0x9123_683E;
+    pub const CGROUP_SUPER_MAGIC: i64 = 0x0027_E0EB;
+    pub const CGROUP2_SUPER_MAGIC: i64 = 0x6367_7270;
+    pub const CIFS_SUPER_MAGIC: i64 = 0xFF53_4D42;
+    pub const CRAMFS_MAGIC: i64 = 0x28CD_3D45;
+    pub const DEBUGFS_MAGIC: i64 = 0x6462_6720;
+    pub const DEVPTS_SUPER_MAGIC: i64 = 0x1CD1;
+    /// Shared by ext2, ext3 and ext4.
+    pub const EXT4_SUPER_MAGIC: i64 = 0xEF53;
+    pub const F2FS_SUPER_MAGIC: i64 = 0xF2F5_2010;
+    pub const FUSE_SUPER_MAGIC: i64 = 0x6573_5546;
+    pub const HUGETLBFS_MAGIC: i64 = 0x9584_58F6;
+    pub const ISOFS_SUPER_MAGIC: i64 = 0x9660;
+    pub const JFFS2_SUPER_MAGIC: i64 = 0x72B6;
+    pub const MSDOS_SUPER_MAGIC: i64 = 0x4D44;
+    pub const NFS_SUPER_MAGIC: i64 = 0x6969;
+    pub const NTFS_SB_MAGIC: i64 = 0x5346_544E;
+    pub const OVERLAYFS_SUPER_MAGIC: i64 = 0x794C_7630;
+    pub const PROC_SUPER_MAGIC: i64 = 0x9FA0;
+    pub const PSTOREFS_MAGIC: i64 = 0x6165_676C;
+    pub const RAMFS_MAGIC: i64 = 0x8584_58F6;
+    pub const REISERFS_SUPER_MAGIC: i64 = 0x5265_4973;
+    pub const SECURITYFS_MAGIC: i64 = 0x7363_6673;
+    pub const SELINUX_MAGIC: i64 = 0xF97C_FF8C;
+    pub const SMB2_MAGIC_NUMBER: i64 = 0xFE53_4D42;
+    pub const SQUASHFS_MAGIC: i64 = 0x7371_7368;
+    pub const SYSFS_MAGIC: i64 = 0x6265_6572;
+    pub const TMPFS_MAGIC: i64 = 0x0102_1994;
+    pub const TRACEFS_MAGIC: i64 = 0x7472_6163;
+    pub const UDF_SUPER_MAGIC: i64 = 0x1501_3346;
+    pub const XFS_SUPER_MAGIC: i64 = 0x5846_5342;
+    pub const ZFS_SUPER_MAGIC: i64 = 0x2FC1_2FC1;
+}
+// spell-checker:enable
+
+/// Map a `statfs(2)` `f_type` magic number to a filesystem name, or
+/// `"unknown"` if the number is not one of the [`fs_magic`] constants.
+pub fn fs_magic_name(f_type: i64) -> &'static str {
+    use fs_magic::*;
+    // spell-checker:disable
+    match f_type {
+        BINFMTFS_MAGIC => "binfmt_misc",
+        BPF_FS_MAGIC => "bpf",
+        BTRFS_SUPER_MAGIC => "btrfs",
+        CGROUP_SUPER_MAGIC => "cgroup",
+        CGROUP2_SUPER_MAGIC => "cgroup2",
+        CIFS_SUPER_MAGIC => "cifs",
+        CRAMFS_MAGIC => "cramfs",
+        DEBUGFS_MAGIC => "debugfs",
+        DEVPTS_SUPER_MAGIC => "devpts",
+        EXT4_SUPER_MAGIC => "ext4",
+        F2FS_SUPER_MAGIC => "f2fs",
+        FUSE_SUPER_MAGIC => "fuse",
+        HUGETLBFS_MAGIC => "hugetlbfs",
+        ISOFS_SUPER_MAGIC => "iso9660",
+        JFFS2_SUPER_MAGIC => "jffs2",
+        MSDOS_SUPER_MAGIC => "msdos",
+        NFS_SUPER_MAGIC => "nfs",
+        NTFS_SB_MAGIC => "ntfs",
+        OVERLAYFS_SUPER_MAGIC => "overlay",
+        PROC_SUPER_MAGIC => "proc",
+        PSTOREFS_MAGIC => "pstore",
+        RAMFS_MAGIC => "ramfs",
+        REISERFS_SUPER_MAGIC => "reiserfs",
+        SECURITYFS_MAGIC => "securityfs",
+        SELINUX_MAGIC => "selinuxfs",
+        SMB2_MAGIC_NUMBER => "smb2",
+        SQUASHFS_MAGIC => "squashfs",
+        SYSFS_MAGIC => "sysfs",
+        TMPFS_MAGIC => "tmpfs",
+        TRACEFS_MAGIC => "tracefs",
+        UDF_SUPER_MAGIC => "udf",
+        XFS_SUPER_MAGIC => "xfs",
+        ZFS_SUPER_MAGIC => "zfs",
+        _ => "unknown",
+    }
+    // spell-checker:enable
 }
 
 #[cfg(unix)]
@@ -1053,6 +1190,14 @@ mod tests {
         // spell-checker:enable
     }
 
+    #[test]
+    fn test_fs_magic_name() {
+        assert_eq!("ext4", fs_magic_name(fs_magic::EXT4_SUPER_MAGIC));
+        assert_eq!("tmpfs", fs_magic_name(fs_magic::TMPFS_MAGIC));
+        assert_eq!("proc", fs_magic_name(fs_magic::PROC_SUPER_MAGIC));
+        assert_eq!("unknown", fs_magic_name(0x1234));
+    }
+
     #[test]
     #[cfg(any(target_os = "linux", target_os = "android"))]
     fn test_mountinfo() {
@@ -82,9 +82,75 @@ impl<B: BufRead> Iterator for Lines<B> {
     }
 }
 
+/// An iterator over delimiter-terminated records of an instance of `BufRead`.
+///
+/// Unlike [`Lines`], the yielded records do not include the trailing
+/// delimiter byte. This is convenient for utilities that support a
+/// `--zero-terminated` mode, where records are separated by the null
+/// character (`b'\0'`) instead of a newline: construct the iterator
+/// with [`ByteLines::zero_terminated`] and process each record without
+/// having to strip the separator manually.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use std::io::Cursor;
+///
+/// let cursor = Cursor::new(b"x\0y\0z");
+/// let mut it = ByteLines::zero_terminated(cursor).map(|l| l.unwrap());
+///
+/// assert_eq!(it.next(), Some(Vec::from("x")));
+/// assert_eq!(it.next(), Some(Vec::from("y")));
+/// assert_eq!(it.next(), Some(Vec::from("z")));
+/// assert_eq!(it.next(), None);
+/// ```
+pub struct ByteLines<B> {
+    buf: B,
+    delimiter: u8,
+}
+
+impl<B: BufRead> ByteLines<B> {
+    /// Creates an iterator over newline-delimited records.
+    pub fn new(reader: B) -> Self {
+        Self::with_delimiter(reader, b'\n')
+    }
+
+    /// Creates an iterator over records delimited by `delimiter`.
+    pub fn with_delimiter(reader: B, delimiter: u8) -> Self {
+        Self {
+            buf: reader,
+            delimiter,
+        }
+    }
+
+    /// Creates an iterator over null-delimited records, as used by the
+    /// `--zero-terminated` option of several utilities.
+    pub fn zero_terminated(reader: B) -> Self {
+        Self::with_delimiter(reader, 0)
+    }
+}
+
+impl<B: BufRead> Iterator for ByteLines<B> {
+    type Item = std::io::Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut buf = Vec::new();
+        match self.buf.read_until(self.delimiter, &mut buf) {
+            Ok(0) => None,
+            Err(e) => Some(Err(e)),
+            Ok(_n) => {
+                if buf.last() == Some(&self.delimiter) {
+                    buf.pop();
+                }
+                Some(Ok(buf))
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::lines::lines;
+    use crate::lines::{ByteLines, lines};
     use std::io::Cursor;
 
     #[test]
@@ -110,4 +176,26 @@ mod tests {
         assert_eq!(it.next(), Some(Vec::from("z\0")));
         assert_eq!(it.next(), None);
     }
+
+    #[test]
+    fn test_byte_lines() {
+        let cursor = Cursor::new(b"x\ny\nz");
+        let mut it = ByteLines::new(cursor).map(|l| l.unwrap());
+
+        assert_eq!(it.next(), Some(Vec::from("x")));
+        assert_eq!(it.next(), Some(Vec::from("y")));
+        assert_eq!(it.next(), Some(Vec::from("z")));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_byte_lines_zero_terminated() {
+        let cursor = Cursor::new(b"x\0y\0z\0");
+        let mut it = ByteLines::zero_terminated(cursor).map(|l| l.unwrap());
+
+        assert_eq!(it.next(), Some(Vec::from("x")));
+        assert_eq!(it.next(), Some(Vec::from("y")));
+        assert_eq!(it.next(), Some(Vec::from("z")));
+        assert_eq!(it.next(), None);
+    }
 }
@@ -33,39 +33,84 @@ pub fn parse_numeric(fperm: u32, mut mode: &str, considering_dir: bool) -> Resul
 }
 
 pub fn parse_symbolic(
-    mut fperm: u32,
-    mut mode: &str,
+    fperm: u32,
+    mode: &str,
     umask: u32,
     considering_dir: bool,
 ) -> Result<u32, String> {
+    let changes = parse_symbolic_mode_str(mode)?;
+    Ok(apply_mode_changes(&changes, fperm, umask, considering_dir))
+}
+
+/// A single change from a symbolic mode string, e.g. the `+x` in `go+x-w`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolicModeChange {
+    /// Mask of the permission bits affected, from the `ugoa` levels prefix.
+    pub mask: u32,
+    /// Whether the umask limits the change (no explicit `ugoa` prefix).
+    pub respect_umask: bool,
+    /// The operator: one of `+`, `-` or `=`.
+    pub op: char,
+    /// The permissions of the change, e.g. `rw`. Kept in symbolic form
+    /// because `X` and the `ugo` copies depend on the mode and file type
+    /// they are applied to.
+    pub perms: String,
+}
+
+/// Parse a symbolic mode string into the list of changes it describes.
+///
+/// Unlike [`parse_symbolic`], this does not need the current mode: the
+/// result can be inspected, stored, and applied to any number of files
+/// with [`apply_mode_changes`].
+pub fn parse_symbolic_mode_str(mut mode: &str) -> Result<Vec<SymbolicModeChange>, String> {
     let (mask, pos) = parse_levels(mode);
     if pos == mode.len() {
         return Err(format!("invalid mode ({mode})"));
     }
     let respect_umask = pos == 0;
     mode = &mode[pos..];
+    let mut changes = Vec::new();
     while !mode.is_empty() {
         let (op, pos) = parse_op(mode)?;
         mode = &mode[pos..];
-        let (mut srwx, pos) = parse_change(mode, fperm, considering_dir);
-        if respect_umask {
+        let pos = parse_perms_len(mode);
+        changes.push(SymbolicModeChange {
+            mask,
+            respect_umask,
+            op,
+            perms: mode[..pos].to_string(),
+        });
+        mode = &mode[pos..];
+    }
+    Ok(changes)
+}
+
+/// Apply the changes of a parsed symbolic mode to the mode `fperm`.
+pub fn apply_mode_changes(
+    changes: &[SymbolicModeChange],
+    mut fperm: u32,
+    umask: u32,
+    considering_dir: bool,
+) -> u32 {
+    for change in changes {
+        let (mut srwx, _) = parse_change(&change.perms, fperm, considering_dir);
+        if change.respect_umask {
             srwx &= !umask;
         }
-        mode = &mode[pos..];
-        match op {
-            '+' => fperm |= srwx & mask,
-            '-' => fperm &= !(srwx & mask),
+        match change.op {
+            '+' => fperm |= srwx & change.mask,
+            '-' => fperm &= !(srwx & change.mask),
             '=' => {
                 if considering_dir {
                     // keep the setgid and setuid bits for directories
                     srwx |= fperm & (0o4000 | 0o2000);
                 }
-                fperm = (fperm & !mask) | (srwx & mask);
+                fperm = (fperm & !change.mask) | (srwx & change.mask);
             }
             _ => unreachable!(),
         }
     }
-    Ok(fperm)
+    fperm
 }
 
 fn parse_levels(mode: &str) -> (u32, usize) {
@@ -100,6 +145,22 @@ fn parse_op(mode: &str) -> Result<(char, usize), String> {
     }
 }
 
+/// Length of the leading permission characters of `mode`.
+///
+/// Consumes the same characters as [`parse_change`]: a run of `rwxXst`,
+/// or a single `u`, `g` or `o` copying another level's permissions.
+fn parse_perms_len(mode: &str) -> usize {
+    let mut pos = 0;
+    for ch in mode.chars() {
+        match ch {
+            'r' | 'w' | 'x' | 'X' | 's' | 't' => pos += 1,
+            'u' | 'g' | 'o' if pos == 0 => return 1,
+            _ => break,
+        }
+    }
+    pos
+}
+
 fn parse_change(mode: &str, fperm: u32, considering_dir: bool) -> (u32, usize) {
     let mut srwx = 0;
     let mut pos = 0;
@@ -156,12 +217,17 @@ pub fn parse_mode(mode: &str) -> Result<mode_t, String> {
     result.map(|mode| mode as mode_t)
 }
 
+/// Get the umask of the current process.
+///
+/// On Linux and Android the umask is read from `/proc/self/status`, which
+/// does not modify it. Elsewhere there is no portable way to read the umask
+/// without changing it: it is replaced and quickly set back, which can race
+/// with concurrent threads creating files while the umask is zeroed.
 pub fn get_umask() -> u32 {
-    // There's no portable way to read the umask without changing it.
-    // We have to replace it and then quickly set it back, hopefully before
-    // some other thread is affected.
-    // On modern Linux kernels the current umask could instead be read
-    // from /proc/self/status. But that's a lot of work.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    if let Some(mask) = read_umask_from_proc() {
+        return mask;
+    }
     // SAFETY: umask always succeeds and doesn't operate on memory. Races are
     // possible but it can't violate Rust's guarantees.
     let mask = unsafe { umask(0) };
@@ -182,6 +248,17 @@ pub fn get_umask() -> u32 {
     return mask as u32;
 }
 
+/// Read the umask from `/proc/self/status`, e.g. "Umask:\t0022".
+///
+/// Returns [`None`] on kernels too old to report it (before Linux 4.7) or if
+/// procfs is not mounted.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn read_umask_from_proc() -> Option<u32> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let mask = status.lines().find_map(|l| l.strip_prefix("Umask:"))?;
+    u32::from_str_radix(mask.trim(), 8).ok()
+}
+
 // Iterate 'args' and delete the first occurrence
 // of a prefix '-' if it's associated with MODE
 // e.g. "chmod -v -xw -R FILE" -> "chmod -v xw -R FILE"
@@ -216,6 +293,38 @@ mod test {
         assert_eq!(super::parse_mode("g-r").unwrap(), 0o626);
     }
 
+    #[test]
+    fn symbolic_mode_changes() {
+        let changes = super::parse_symbolic_mode_str("go+x-w").unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!((changes[0].op, changes[0].perms.as_str()), ('+', "x"));
+        assert_eq!((changes[1].op, changes[1].perms.as_str()), ('-', "w"));
+        assert!(!changes[0].respect_umask);
+        // The same parsed changes can be applied to any number of modes.
+        assert_eq!(super::apply_mode_changes(&changes, 0o666, 0o022, false), 0o655);
+        assert_eq!(super::apply_mode_changes(&changes, 0o640, 0o022, false), 0o651);
+
+        let changes = super::parse_symbolic_mode_str("=rwx").unwrap();
+        assert!(changes[0].respect_umask);
+        assert_eq!(super::apply_mode_changes(&changes, 0o644, 0o022, false), 0o755);
+
+        assert!(super::parse_symbolic_mode_str("u").is_err());
+        assert!(super::parse_symbolic_mode_str("u?x").is_err());
+    }
+
+    // Only Linux and Android read the umask from /proc; elsewhere
+    // get_umask() still briefly zeroes the umask, so concurrent calls
+    // could legitimately observe the replaced value.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[test]
+    fn get_umask_from_concurrent_threads() {
+        let expected = super::get_umask();
+        let threads: Vec<_> = (0..8).map(|_| std::thread::spawn(super::get_umask)).collect();
+        for thread in threads {
+            assert_eq!(thread.join().unwrap(), expected);
+        }
+    }
+
     #[test]
     fn numeric_modes() {
         assert_eq!(super::parse_mode("644").unwrap(), 0o644);
@@ -25,6 +25,30 @@ pub fn pipe() -> Result<(File, File)> {
     Ok((File::from(read), File::from(write)))
 }
 
+/// Like [`pipe`], but request a buffer capacity of `capacity` bytes.
+///
+/// A larger buffer lets more data flow through the pipe per context switch.
+/// The capacity is only a hint: the kernel rounds it up to a power of two and
+/// refuses sizes above `/proc/sys/fs/pipe-max-size` for unprivileged
+/// processes. In that case, and on platforms without the `F_SETPIPE_SZ`
+/// fcntl, the pipe is still returned with its default capacity.
+pub fn pipe_with_capacity(capacity: usize) -> Result<(File, File)> {
+    let (read, write) = pipe()?;
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        use nix::fcntl::{fcntl, FcntlArg};
+        use std::os::fd::AsRawFd;
+
+        let _ = fcntl(
+            write.as_raw_fd(),
+            FcntlArg::F_SETPIPE_SZ(capacity as nix::libc::c_int),
+        );
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    let _ = capacity;
+    Ok((read, write))
+}
+
 /// Less noisy wrapper around [`nix::fcntl::splice`].
 ///
 /// Up to `len` bytes are moved from `source` to `target`. Returns the number
@@ -62,3 +86,38 @@ pub fn splice_exact(source: &impl AsFd, target: &impl AsFd, len: usize) -> Resul
 pub fn vmsplice(target: &impl AsFd, bytes: &[u8]) -> Result<usize> {
     nix::fcntl::vmsplice(target, &[IoSlice::new(bytes)], SpliceFFlags::empty())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[test]
+    fn test_pipe_with_capacity() {
+        use nix::fcntl::{fcntl, FcntlArg};
+        use std::os::fd::AsRawFd;
+
+        let capacity = 1024 * 1024;
+        let (read, write) = pipe_with_capacity(capacity).unwrap();
+        // The kernel applies the capacity to the whole pipe, so both ends
+        // report it.
+        for end in [&read, &write] {
+            let size = fcntl(end.as_raw_fd(), FcntlArg::F_GETPIPE_SZ).unwrap();
+            assert!(size as usize >= capacity);
+        }
+    }
+
+    #[test]
+    fn test_pipe_with_capacity_excessive_size() {
+        // An absurd capacity is rejected by the kernel; the pipe must still
+        // work with its default capacity.
+        use std::io::{Read, Write};
+
+        let (mut read, mut write) = pipe_with_capacity(usize::MAX).unwrap();
+        write.write_all(b"hello").unwrap();
+        drop(write);
+        let mut buf = Vec::new();
+        read.read_to_end(&mut buf).unwrap();
+        assert_eq!(buf, b"hello");
+    }
+}
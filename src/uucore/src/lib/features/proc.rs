@@ -0,0 +1,220 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+// spell-checker:ignore meminfo loadavg Shmem writeback
+
+//! Parsers for commonly used `/proc` files.
+//!
+//! Several utilities need the memory, load or mount information the
+//! kernel exposes under `/proc`. This module provides one parser per
+//! file so that they do not each grow their own ad-hoc reader.
+
+use std::fs;
+use std::io;
+
+/// Memory usage information from `/proc/meminfo`, in bytes.
+///
+/// Fields missing from the kernel's output (older kernels do not report
+/// `MemAvailable`, for example) are left at zero.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MemInfo {
+    pub mem_total: u64,
+    pub mem_free: u64,
+    pub mem_available: u64,
+    pub buffers: u64,
+    pub cached: u64,
+    pub shmem: u64,
+    pub swap_total: u64,
+    pub swap_free: u64,
+}
+
+/// One line of `/proc/mounts`, in the order the fields appear there.
+///
+/// Unlike [`crate::fsext::MountInfo`] this is a plain representation of
+/// the mount table without any interpretation of the entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MountEntry {
+    pub device: String,
+    pub mount_point: String,
+    pub fs_type: String,
+    pub options: String,
+}
+
+/// Read and parse `/proc/meminfo`.
+pub fn read_meminfo() -> io::Result<MemInfo> {
+    parse_meminfo(&fs::read_to_string("/proc/meminfo")?)
+}
+
+/// Read the 1, 5 and 15 minute load averages from `/proc/loadavg`.
+pub fn read_loadavg() -> io::Result<(f64, f64, f64)> {
+    parse_loadavg(&fs::read_to_string("/proc/loadavg")?)
+}
+
+/// Read and parse the mount table from `/proc/mounts`.
+pub fn read_mounts() -> io::Result<Vec<MountEntry>> {
+    parse_mounts(&fs::read_to_string("/proc/mounts")?)
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn parse_meminfo(content: &str) -> io::Result<MemInfo> {
+    let mut info = MemInfo::default();
+    for line in content.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let field = match key {
+            "MemTotal" => &mut info.mem_total,
+            "MemFree" => &mut info.mem_free,
+            "MemAvailable" => &mut info.mem_available,
+            "Buffers" => &mut info.buffers,
+            "Cached" => &mut info.cached,
+            "Shmem" => &mut info.shmem,
+            "SwapTotal" => &mut info.swap_total,
+            "SwapFree" => &mut info.swap_free,
+            _ => continue,
+        };
+        // Values are reported as e.g. "16384 kB"; sizes are always in kB.
+        let value = value.trim().trim_end_matches(" kB");
+        *field = value
+            .parse::<u64>()
+            .map_err(|_| invalid_data(format!("invalid meminfo value for {key}: {value}")))?
+            * 1024;
+    }
+    Ok(info)
+}
+
+fn parse_loadavg(content: &str) -> io::Result<(f64, f64, f64)> {
+    let mut values = content
+        .split_whitespace()
+        .take(3)
+        .map(str::parse::<f64>)
+        .collect::<Result<Vec<f64>, _>>()
+        .map_err(|_| invalid_data(format!("invalid loadavg: {}", content.trim_end())))?
+        .into_iter();
+    match (values.next(), values.next(), values.next()) {
+        (Some(one), Some(five), Some(fifteen)) => Ok((one, five, fifteen)),
+        _ => Err(invalid_data(format!(
+            "invalid loadavg: {}",
+            content.trim_end()
+        ))),
+    }
+}
+
+fn parse_mounts(content: &str) -> io::Result<Vec<MountEntry>> {
+    content
+        .lines()
+        .map(|line| {
+            let mut fields = line.split_whitespace();
+            match (fields.next(), fields.next(), fields.next(), fields.next()) {
+                (Some(device), Some(mount_point), Some(fs_type), Some(options)) => Ok(MountEntry {
+                    device: unescape_octal(device),
+                    mount_point: unescape_octal(mount_point),
+                    fs_type: fs_type.to_string(),
+                    options: options.to_string(),
+                }),
+                _ => Err(invalid_data(format!("invalid mount entry: {line}"))),
+            }
+        })
+        .collect()
+}
+
+/// Undo the octal escaping the kernel applies to whitespace and
+/// backslashes in mount fields, e.g. `\040` for a space.
+///
+/// The escapes encode raw bytes, so multi-byte UTF-8 sequences are only
+/// decoded once all of their bytes have been unescaped.
+fn unescape_octal(field: &str) -> String {
+    let mut result = Vec::with_capacity(field.len());
+    let mut bytes = field.bytes();
+    while let Some(b) = bytes.next() {
+        if b != b'\\' {
+            result.push(b);
+            continue;
+        }
+        let digits: Vec<u8> = bytes.clone().take(3).collect();
+        match std::str::from_utf8(&digits)
+            .ok()
+            .and_then(|digits| u8::from_str_radix(digits, 8).ok())
+        {
+            Some(byte) if digits.len() == 3 => {
+                result.push(byte);
+                bytes.nth(2);
+            }
+            _ => result.push(b),
+        }
+    }
+    String::from_utf8_lossy(&result).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_meminfo() {
+        let content = "MemTotal:       16384 kB\n\
+                       MemFree:         8192 kB\n\
+                       MemAvailable:   12288 kB\n\
+                       Buffers:          512 kB\n\
+                       Cached:          1024 kB\n\
+                       SwapTotal:       2048 kB\n\
+                       SwapFree:        2048 kB\n\
+                       Shmem:            256 kB\n\
+                       Dirty:             16 kB\n";
+        let info = parse_meminfo(content).unwrap();
+        assert_eq!(info.mem_total, 16384 * 1024);
+        assert_eq!(info.mem_available, 12288 * 1024);
+        assert_eq!(info.shmem, 256 * 1024);
+        assert_eq!(info.swap_free, 2048 * 1024);
+    }
+
+    #[test]
+    fn test_parse_meminfo_rejects_garbage() {
+        assert!(parse_meminfo("MemTotal:       lots\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_loadavg() {
+        let loadavg = parse_loadavg("0.20 0.18 0.12 1/1255 12345\n").unwrap();
+        assert_eq!(loadavg, (0.20, 0.18, 0.12));
+        assert!(parse_loadavg("0.20 0.18\n").is_err());
+        assert!(parse_loadavg("one two three\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_mounts() {
+        let content = "/dev/sda1 / ext4 rw,relatime 0 0\n\
+                       tmpfs /mnt/with\\040space tmpfs rw 0 0\n";
+        let mounts = parse_mounts(content).unwrap();
+        assert_eq!(mounts.len(), 2);
+        assert_eq!(mounts[0].device, "/dev/sda1");
+        assert_eq!(mounts[0].fs_type, "ext4");
+        assert_eq!(mounts[1].mount_point, "/mnt/with space");
+        assert!(parse_mounts("tmpfs /mnt\n").is_err());
+    }
+
+    #[test]
+    fn test_unescape_octal_utf8() {
+        // `ä` is escaped byte-by-byte, so the UTF-8 sequence must be
+        // reassembled from the unescaped bytes.
+        assert_eq!(unescape_octal("/mnt/b\\303\\244r"), "/mnt/bär");
+        assert_eq!(unescape_octal("\\134"), "\\");
+        // Incomplete escapes are kept verbatim.
+        assert_eq!(unescape_octal("trailing\\04"), "trailing\\04");
+    }
+
+    #[test]
+    fn test_read_real_proc_files() {
+        let info = read_meminfo().unwrap();
+        assert!(info.mem_total > 0);
+        let (one, five, fifteen) = read_loadavg().unwrap();
+        assert!(one >= 0.0 && five >= 0.0 && fifteen >= 0.0);
+        let mounts = read_mounts().unwrap();
+        assert!(mounts.iter().any(|m| m.mount_point == "/proc"));
+    }
+}
@@ -10,8 +10,11 @@
 use libc::{gid_t, pid_t, uid_t};
 #[cfg(not(target_os = "redox"))]
 use nix::errno::Errno;
+use std::env;
+use std::ffi::OsStr;
 use std::io;
 use std::process::Child;
+use std::process::Command;
 use std::process::ExitStatus;
 use std::thread;
 use std::time::{Duration, Instant};
@@ -69,6 +72,47 @@ pub fn getsid(pid: i32) -> Result<pid_t, Errno> {
     }
 }
 
+/// The environment variables that [`CommandExt::with_env_reset`] keeps.
+const SAFE_ENV_VARS: [&str; 4] = ["PATH", "HOME", "TMPDIR", "TERM"];
+
+/// Missing methods for Command objects
+pub trait CommandExt {
+    /// Run the command with a clean environment, keeping only a minimal
+    /// safe set of variables (`PATH`, `HOME`, `TMPDIR` and `TERM`) from
+    /// the current environment.
+    fn with_env_reset(self) -> Self;
+
+    /// Run the command with exactly the given environment variables.
+    fn with_env_from<I, K, V>(self, vars: I) -> Self
+    where
+        I: Iterator<Item = (K, V)>,
+        K: AsRef<OsStr>,
+        V: AsRef<OsStr>;
+}
+
+impl CommandExt for Command {
+    fn with_env_reset(mut self) -> Self {
+        self.env_clear();
+        for var in SAFE_ENV_VARS {
+            if let Some(value) = env::var_os(var) {
+                self.env(var, value);
+            }
+        }
+        self
+    }
+
+    fn with_env_from<I, K, V>(mut self, vars: I) -> Self
+    where
+        I: Iterator<Item = (K, V)>,
+        K: AsRef<OsStr>,
+        V: AsRef<OsStr>,
+    {
+        self.env_clear();
+        self.envs(vars);
+        self
+    }
+}
+
 /// Missing methods for Child objects
 pub trait ChildExt {
     /// Send a signal to a Child process.
@@ -153,4 +197,16 @@ mod tests {
         // This might caused tests failure but the probability is low.
         assert!(getsid(999_999).is_err());
     }
+
+    #[test]
+    fn test_command_ext_env() {
+        let cmd = Command::new("env").with_env_from([("FOO", "bar")].into_iter());
+        let envs: Vec<_> = cmd.get_envs().collect();
+        assert_eq!(envs, [(OsStr::new("FOO"), Some(OsStr::new("bar")))]);
+
+        let cmd = Command::new("env").with_env_reset();
+        for (key, _) in cmd.get_envs() {
+            assert!(SAFE_ENV_VARS.iter().any(|var| OsStr::new(var) == key));
+        }
+    }
 }
@@ -0,0 +1,66 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Progress reporting on stderr for long-running operations.
+//!
+//! The bar is drawn on stderr and redrawn every 250 ms; indicatif hides
+//! it automatically when stderr is not a terminal. Wrap a reader with
+//! [`ProgressBar::wrap_read`] to advance the bar as the input is
+//! consumed, and call [`ProgressBar::finish_and_clear`] when done.
+
+pub use indicatif::ProgressBar;
+use indicatif::ProgressStyle;
+use std::time::Duration;
+
+/// How often a progress bar is redrawn.
+const TICK_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Create a progress bar for `total` expected bytes, labeled with `label`.
+///
+/// When the total is unknown, the bar only shows the bytes read so far
+/// and the throughput.
+pub fn progress_bar(label: &str, total: Option<u64>) -> ProgressBar {
+    let bar = match total {
+        Some(total) => ProgressBar::new(total).with_style(
+            ProgressStyle::with_template(
+                "{msg}: [{elapsed_precise}] {wide_bar} {bytes:>7}/{total_bytes:7} ({bytes_per_sec})",
+            )
+            .unwrap(),
+        ),
+        None => ProgressBar::new_spinner().with_style(
+            ProgressStyle::with_template("{msg}: [{elapsed_precise}] {bytes} ({bytes_per_sec})")
+                .unwrap(),
+        ),
+    };
+    let bar = bar.with_message(label.to_owned());
+    bar.enable_steady_tick(TICK_INTERVAL);
+    bar
+}
+
+#[cfg(test)]
+mod tests {
+    use super::progress_bar;
+    use std::io::Read;
+
+    #[test]
+    fn test_progress_bar_counts_read_bytes() {
+        let bar = progress_bar("test", Some(10));
+        let mut reader = bar.wrap_read(&b"0123456789"[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).unwrap();
+        assert_eq!(bar.position(), 10);
+        bar.finish_and_clear();
+    }
+
+    #[test]
+    fn test_progress_bar_unknown_total() {
+        let bar = progress_bar("test", None);
+        let mut reader = bar.wrap_read(&b"abc"[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).unwrap();
+        assert_eq!(bar.position(), 3);
+        bar.finish_and_clear();
+    }
+}
@@ -45,6 +45,11 @@ pub enum QuotingStyle {
         /// Whether to show control and non-unicode characters, or replace them with `?`.
         show_control: bool,
     },
+
+    /// Escape the name as a C string, but quote it with the quotation
+    /// marks preferred by the current locale.
+    /// Used in, e.g., `ls --quoting-style=locale`.
+    Locale,
 }
 
 /// The type of quotes to use when escaping a name as a C string.
@@ -230,6 +235,27 @@ impl EscapedChar {
             _ => self,
         }
     }
+
+    /// Write the escaped character directly to `buf`, without going
+    /// through an intermediate `String` or `char` iteration. This is
+    /// the preferred way to accumulate escaped names in hot paths.
+    fn write_to(self, buf: &mut Vec<u8>) {
+        let mut utf8_buf = [0; 4];
+        match self.state {
+            EscapeState::Done => {}
+            EscapeState::Char(c) | EscapeState::ForceQuote(c) => {
+                buf.extend_from_slice(c.encode_utf8(&mut utf8_buf).as_bytes());
+            }
+            EscapeState::Backslash(c) => {
+                buf.push(b'\\');
+                buf.extend_from_slice(c.encode_utf8(&mut utf8_buf).as_bytes());
+            }
+            EscapeState::Octal(iter) => {
+                // Octal escapes only consist of ASCII characters.
+                buf.extend(iter.map(|c| c as u8));
+            }
+        }
+    }
 }
 
 impl Iterator for EscapedChar {
@@ -259,7 +285,6 @@ fn bytes_start_with(bytes: &[u8], pattern: &[u8]) -> bool {
 fn shell_without_escape(name: &[u8], quotes: Quotes, show_control_chars: bool) -> (Vec<u8>, bool) {
     let mut must_quote = false;
     let mut escaped_str = Vec::with_capacity(name.len());
-    let mut utf8_buf = vec![0; 4];
 
     for s in name.utf8_chunks() {
         for c in s.valid().chars() {
@@ -274,15 +299,11 @@ fn shell_without_escape(name: &[u8], quotes: Quotes, show_control_chars: bool) -
 
             match escaped.state {
                 EscapeState::Backslash('\'') => escaped_str.extend_from_slice(b"'\\''"),
-                EscapeState::ForceQuote(x) => {
+                EscapeState::ForceQuote(_) => {
                     must_quote = true;
-                    escaped_str.extend_from_slice(x.encode_utf8(&mut utf8_buf).as_bytes());
-                }
-                _ => {
-                    for c in escaped {
-                        escaped_str.extend_from_slice(c.encode_utf8(&mut utf8_buf).as_bytes());
-                    }
+                    escaped.write_to(&mut escaped_str);
                 }
+                _ => escaped.write_to(&mut escaped_str),
             }
         }
 
@@ -302,26 +323,26 @@ fn shell_with_escape(name: &[u8], quotes: Quotes) -> (Vec<u8>, bool) {
     // because e.g. \b\n is escaped as $'\b\n' and not like $'b'$'n'
     let mut in_dollar = false;
     let mut must_quote = false;
-    let mut escaped_str = String::with_capacity(name.len());
+    let mut escaped_str = Vec::with_capacity(name.len());
 
     for s in name.utf8_chunks() {
         for c in s.valid().chars() {
             let escaped = EscapedChar::new_shell(c, true, quotes);
             match escaped.state {
-                EscapeState::Char(x) => {
+                EscapeState::Char(_) => {
                     if in_dollar {
-                        escaped_str.push_str("''");
+                        escaped_str.extend_from_slice(b"''");
                         in_dollar = false;
                     }
-                    escaped_str.push(x);
+                    escaped.write_to(&mut escaped_str);
                 }
-                EscapeState::ForceQuote(x) => {
+                EscapeState::ForceQuote(_) => {
                     if in_dollar {
-                        escaped_str.push_str("''");
+                        escaped_str.extend_from_slice(b"''");
                         in_dollar = false;
                     }
                     must_quote = true;
-                    escaped_str.push(x);
+                    escaped.write_to(&mut escaped_str);
                 }
                 // Single quotes are not put in dollar expressions, but are escaped
                 // if the string also contains double quotes. In that case, they must
@@ -329,36 +350,31 @@ fn shell_with_escape(name: &[u8], quotes: Quotes) -> (Vec<u8>, bool) {
                 EscapeState::Backslash('\'') => {
                     must_quote = true;
                     in_dollar = false;
-                    escaped_str.push_str("'\\''");
+                    escaped_str.extend_from_slice(b"'\\''");
                 }
                 _ => {
                     if !in_dollar {
-                        escaped_str.push_str("'$'");
+                        escaped_str.extend_from_slice(b"'$'");
                         in_dollar = true;
                     }
                     must_quote = true;
-                    for char in escaped {
-                        escaped_str.push(char);
-                    }
+                    escaped.write_to(&mut escaped_str);
                 }
             }
         }
         if !s.invalid().is_empty() {
             if !in_dollar {
-                escaped_str.push_str("'$'");
+                escaped_str.extend_from_slice(b"'$'");
                 in_dollar = true;
             }
             must_quote = true;
-            let escaped_bytes: String = s
-                .invalid()
-                .iter()
-                .flat_map(|b| EscapedChar::new_octal(*b))
-                .collect();
-            escaped_str.push_str(&escaped_bytes);
+            for b in s.invalid() {
+                EscapedChar::new_octal(*b).write_to(&mut escaped_str);
+            }
         }
     }
     must_quote = must_quote || bytes_start_with(name, SPECIAL_SHELL_CHARS_START);
-    (escaped_str.into(), must_quote)
+    (escaped_str, must_quote)
 }
 
 /// Return a set of characters that implies quoting of the word in
@@ -372,6 +388,42 @@ fn shell_escaped_char_set(is_dirname: bool) -> &'static [u8] {
     &ESCAPED_CHARS[start_index..]
 }
 
+/// Return the quotation marks preferred by the current locale, as an
+/// (opening mark, closing mark, inner quotes) triple. The inner quotes
+/// determine which plain ASCII quote character is backslash-escaped
+/// inside the quoted name.
+///
+/// The locale is taken from the `LC_ALL`, `LC_MESSAGES` and `LANG`
+/// environment variables, in that order, mirroring glibc's lookup.
+/// Unknown locales fall back to plain double quotes.
+fn locale_quotes() -> (&'static str, &'static str, Quotes) {
+    let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .find_map(|var| std::env::var(var).ok().filter(|val| !val.is_empty()))
+        .unwrap_or_default();
+
+    // Only the language part is relevant, e.g. "de" in "de_DE.UTF-8".
+    match locale.split(['_', '.', '@']).next().unwrap_or_default() {
+        "C" | "POSIX" | "en" => ("'", "'", Quotes::Single),
+        "de" => ("\u{201E}", "\u{201C}", Quotes::Double),
+        "fr" => ("\u{00AB}", "\u{00BB}", Quotes::Double),
+        _ => ("\"", "\"", Quotes::Double),
+    }
+}
+
+/// Write `name`, escaped as a C string (without the surrounding
+/// quotation marks), directly to `buf`.
+fn write_c_escaped(name: &[u8], quotes: Quotes, dirname: bool, buf: &mut Vec<u8>) {
+    for s in name.utf8_chunks() {
+        for c in s.valid().chars() {
+            EscapedChar::new_c(c, quotes, dirname).write_to(buf);
+        }
+        for b in s.invalid() {
+            EscapedChar::new_octal(*b).write_to(buf);
+        }
+    }
+}
+
 /// Escape a name according to the given quoting style.
 ///
 /// This inner function provides an additional flag `dirname` which
@@ -382,39 +434,43 @@ fn escape_name_inner(name: &[u8], style: &QuotingStyle, dirname: bool) -> Vec<u8
             if *show_control {
                 name.to_owned()
             } else {
-                name.utf8_chunks()
-                    .map(|s| {
-                        let valid: String = s
-                            .valid()
-                            .chars()
-                            .flat_map(|c| EscapedChar::new_literal(c).hide_control())
-                            .collect();
-                        let invalid = "?".repeat(s.invalid().len());
-                        valid + &invalid
-                    })
-                    .collect::<String>()
-                    .into()
+                let mut escaped_str = Vec::with_capacity(name.len());
+                for s in name.utf8_chunks() {
+                    for c in s.valid().chars() {
+                        EscapedChar::new_literal(c)
+                            .hide_control()
+                            .write_to(&mut escaped_str);
+                    }
+                    escaped_str.resize(escaped_str.len() + s.invalid().len(), b'?');
+                }
+                escaped_str
             }
         }
         QuotingStyle::C { quotes } => {
-            let escaped_str: String = name
-                .utf8_chunks()
-                .flat_map(|s| {
-                    let valid = s
-                        .valid()
-                        .chars()
-                        .flat_map(|c| EscapedChar::new_c(c, *quotes, dirname));
-                    let invalid = s.invalid().iter().flat_map(|b| EscapedChar::new_octal(*b));
-                    valid.chain(invalid)
-                })
-                .collect::<String>();
-
-            match quotes {
-                Quotes::Single => format!("'{escaped_str}'"),
-                Quotes::Double => format!("\"{escaped_str}\""),
-                Quotes::None => escaped_str,
+            let mut escaped_str = Vec::with_capacity(name.len() + 2);
+            let quote = match quotes {
+                Quotes::Single => Some(b'\''),
+                Quotes::Double => Some(b'"'),
+                Quotes::None => None,
+            };
+
+            if let Some(quote) = quote {
+                escaped_str.push(quote);
+            }
+            write_c_escaped(name, *quotes, dirname, &mut escaped_str);
+            if let Some(quote) = quote {
+                escaped_str.push(quote);
             }
-            .into()
+            escaped_str
+        }
+        QuotingStyle::Locale => {
+            let (open, close, quotes) = locale_quotes();
+            let mut escaped_str = Vec::with_capacity(name.len() + open.len() + close.len());
+
+            escaped_str.extend_from_slice(open.as_bytes());
+            write_c_escaped(name, quotes, dirname, &mut escaped_str);
+            escaped_str.extend_from_slice(close.as_bytes());
+            escaped_str
         }
         QuotingStyle::Shell {
             escape,
@@ -496,6 +552,7 @@ impl fmt::Display for QuotingStyle {
             }
             Self::C { .. } => f.write_str("C"),
             Self::Literal { .. } => f.write_str("literal"),
+            Self::Locale => f.write_str("locale"),
         }
     }
 }
@@ -644,6 +701,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_braces() {
+        // Braces are not special to the shell, so shell quoting styles
+        // must leave them unquoted (matching GNU).
+        check_names(
+            "{one}",
+            &[
+                ("{one}", "literal"),
+                ("{one}", "literal-show"),
+                ("{one}", "escape"),
+                ("\"{one}\"", "c"),
+                ("{one}", "shell"),
+                ("{one}", "shell-show"),
+                ("'{one}'", "shell-always"),
+                ("'{one}'", "shell-always-show"),
+                ("{one}", "shell-escape"),
+                ("'{one}'", "shell-escape-always"),
+            ],
+        );
+    }
+
     #[test]
     fn test_quotes() {
         // One double quote
@@ -371,6 +371,88 @@ pub fn signal_name_by_value(signal_value: usize) -> Option<&'static str> {
     ALL_SIGNALS.get(signal_value).copied()
 }
 
+/// The names of the real-time signals, indexed by their offset from `SIGRTMIN`.
+///
+/// `SIGRTMIN`/`SIGRTMAX` are not constants: the C library reserves a few
+/// real-time signals for its own use, so the offsets have to be applied at
+/// runtime. Pre-rendered names keep the return type of [`signal_name`]
+/// `'static`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+static RT_SIGNAL_NAMES: [&str; 33] = [
+    "RTMIN", "RTMIN+1", "RTMIN+2", "RTMIN+3", "RTMIN+4", "RTMIN+5", "RTMIN+6", "RTMIN+7",
+    "RTMIN+8", "RTMIN+9", "RTMIN+10", "RTMIN+11", "RTMIN+12", "RTMIN+13", "RTMIN+14", "RTMIN+15",
+    "RTMIN+16", "RTMIN+17", "RTMIN+18", "RTMIN+19", "RTMIN+20", "RTMIN+21", "RTMIN+22", "RTMIN+23",
+    "RTMIN+24", "RTMIN+25", "RTMIN+26", "RTMIN+27", "RTMIN+28", "RTMIN+29", "RTMIN+30", "RTMIN+31",
+    "RTMIN+32",
+];
+
+/// Returns the name of a signal number, without the `SIG` prefix.
+///
+/// On Linux, real-time signals are named relative to `SIGRTMIN`
+/// (`RTMIN`, `RTMIN+1`, ..., `RTMAX`), like `kill -l` displays them.
+pub fn signal_name(signum: i32) -> Option<&'static str> {
+    if let Ok(value) = usize::try_from(signum) {
+        if let Some(&name) = ALL_SIGNALS.get(value) {
+            return Some(name);
+        }
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        let (rtmin, rtmax) = (libc::SIGRTMIN(), libc::SIGRTMAX());
+        if (rtmin..=rtmax).contains(&signum) {
+            if signum == rtmax {
+                return Some("RTMAX");
+            }
+            return RT_SIGNAL_NAMES.get((signum - rtmin) as usize).copied();
+        }
+    }
+
+    None
+}
+
+/// Returns the signal number for a name (`SIGTERM` or `TERM`, case
+/// insensitive) or a numeric string (`15`).
+///
+/// On Linux, the real-time signal names `RTMIN`, `RTMIN+N`, `RTMAX-N` and
+/// `RTMAX` are also accepted.
+pub fn signal_number(name: &str) -> Option<i32> {
+    let name_upcase = name.to_uppercase();
+    if let Ok(value) = name_upcase.parse::<i32>() {
+        // A number is valid if it denotes a signal
+        return signal_name(value).map(|_| value);
+    }
+
+    let short_name = name_upcase.strip_prefix("SIG").unwrap_or(&name_upcase);
+
+    if let Some(value) = ALL_SIGNALS.iter().position(|&s| s == short_name) {
+        return Some(value as i32);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        let (rtmin, rtmax) = (libc::SIGRTMIN(), libc::SIGRTMAX());
+        let value = if let Some(offset) = short_name.strip_prefix("RTMIN+") {
+            offset.parse::<i32>().ok().map(|n| rtmin + n)
+        } else if let Some(offset) = short_name.strip_prefix("RTMAX-") {
+            offset.parse::<i32>().ok().map(|n| rtmax - n)
+        } else if short_name == "RTMIN" {
+            Some(rtmin)
+        } else if short_name == "RTMAX" {
+            Some(rtmax)
+        } else {
+            None
+        };
+        if let Some(value) = value {
+            if (rtmin..=rtmax).contains(&value) {
+                return Some(value);
+            }
+        }
+    }
+
+    None
+}
+
 /// Returns the default signal value.
 #[cfg(unix)]
 pub fn enable_pipe_errors() -> Result<(), Errno> {
@@ -418,3 +500,32 @@ fn name() {
         assert_eq!(signal_name_by_value(value), Some(*signal));
     }
 }
+
+#[test]
+fn signal_name_and_number_roundtrip() {
+    for (value, signal) in ALL_SIGNALS.iter().enumerate() {
+        assert_eq!(signal_name(value as i32), Some(*signal));
+        assert_eq!(signal_number(signal), Some(value as i32));
+        assert_eq!(signal_number(&format!("SIG{signal}")), Some(value as i32));
+        assert_eq!(signal_number(&signal.to_lowercase()), Some(value as i32));
+        assert_eq!(signal_number(&value.to_string()), Some(value as i32));
+    }
+    assert_eq!(signal_name(-1), None);
+    assert_eq!(signal_number("NOT_A_SIGNAL"), None);
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn signal_name_and_number_realtime() {
+    let (rtmin, rtmax) = (libc::SIGRTMIN(), libc::SIGRTMAX());
+    assert_eq!(signal_name(rtmin), Some("RTMIN"));
+    assert_eq!(signal_name(rtmin + 1), Some("RTMIN+1"));
+    assert_eq!(signal_name(rtmax), Some("RTMAX"));
+    assert_eq!(signal_number("RTMIN"), Some(rtmin));
+    assert_eq!(signal_number("SIGRTMIN+2"), Some(rtmin + 2));
+    assert_eq!(signal_number("RTMAX-1"), Some(rtmax - 1));
+    assert_eq!(signal_number("RTMAX"), Some(rtmax));
+    // Out of the real-time range
+    assert_eq!(signal_number(&format!("RTMIN+{}", rtmax - rtmin + 1)), None);
+    assert_eq!(signal_number("RTMAX-100"), None);
+}
@@ -99,6 +99,30 @@ impl Digest for Blake3 {
     }
 }
 
+/// xxHash3 in its 128-bit variant.
+pub struct Xxh3(xxhash_rust::xxh3::Xxh3);
+impl Digest for Xxh3 {
+    fn new() -> Self {
+        Self(xxhash_rust::xxh3::Xxh3::new())
+    }
+
+    fn hash_update(&mut self, input: &[u8]) {
+        self.0.update(input);
+    }
+
+    fn hash_finalize(&mut self, out: &mut [u8]) {
+        out.copy_from_slice(&self.0.digest128().to_be_bytes());
+    }
+
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+
+    fn output_bits(&self) -> usize {
+        128
+    }
+}
+
 pub struct Sm3(sm3::Sm3);
 impl Digest for Sm3 {
     fn new() -> Self {
@@ -219,6 +219,25 @@ impl Utmpx {
         !self.user().is_empty() && self.record_type() == USER_PROCESS
     }
 
+    /// Check if the record describes a login session that is still alive.
+    ///
+    /// Records can stay behind in the utmp file after their process has
+    /// died, e.g. when a terminal emulator crashes. A live login session
+    /// has a terminal line and a process that still exists; the process is
+    /// probed with the null signal, which does not affect it.
+    pub fn is_login_session(&self) -> bool {
+        let pid = self.pid();
+        if self.tty_device().is_empty() || pid <= 0 {
+            return false;
+        }
+        // EPERM also means the process exists; we are just not allowed
+        // to signal it.
+        if unsafe { libc::kill(pid, 0) } == 0 {
+            return true;
+        }
+        std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+    }
+
     /// Canonicalize host name using DNS
     pub fn canon_host(&self) -> IOResult<String> {
         let host = self.host();
@@ -152,6 +152,100 @@ pub fn version_cmp(mut a: &str, mut b: &str) -> Ordering {
     Ordering::Equal
 }
 
+/// A single parsed component of a version string: either a run of non-digit
+/// characters or a run of ASCII digits with leading zeroes stripped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Component {
+    NonDigit(String),
+    Digits(String),
+}
+
+impl PartialOrd for Component {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Component {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::NonDigit(a), Self::NonDigit(b)) => version_non_digit_cmp(a, b),
+            (Self::Digits(a), Self::Digits(b)) => a.len().cmp(&b.len()).then_with(|| a.cmp(b)),
+            // Parsing produces strictly alternating components starting with
+            // a (possibly empty) non-digit part, so two keys always have the
+            // same kind of component at the same position.
+            _ => unreachable!("mismatched version component kinds"),
+        }
+    }
+}
+
+/// Split a version string into strictly alternating non-digit and digit
+/// components. The result always starts and ends with a (possibly empty)
+/// non-digit component, so that two parsed versions pair up position by
+/// position just like the comparison loop in [`version_cmp`].
+fn version_components(mut s: &str) -> Vec<Component> {
+    let mut components = Vec::new();
+    loop {
+        let numerical_start = s.find(|c: char| c.is_ascii_digit()).unwrap_or(s.len());
+        components.push(Component::NonDigit(s[..numerical_start].to_string()));
+        s = &s[numerical_start..];
+        if s.is_empty() {
+            return components;
+        }
+
+        let numerical_end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        components.push(Component::Digits(
+            s[..numerical_end].trim_start_matches('0').to_string(),
+        ));
+        s = &s[numerical_end..];
+    }
+}
+
+/// A pre-parsed sort key ordered like [`version_cmp`].
+///
+/// Sorting many names with [`version_cmp`] re-parses both strings on every
+/// comparison. Computing a `VersionSortKey` once per name and sorting by the
+/// keys (e.g. with `sort_by_cached_key`) produces the same order at a
+/// fraction of the cost.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct VersionSortKey {
+    /// Ranks the special cases of [`version_cmp`]: empty strings first, then
+    /// `.`, then `..`, then hidden files, then everything else.
+    special: u8,
+    /// The components with the file ending removed. Compared first, which
+    /// matches [`version_cmp`] comparing the stripped names unless stripping
+    /// makes them identical.
+    stripped: Vec<Component>,
+    /// The components of the complete name, as a tie breaker.
+    full: Vec<Component>,
+}
+
+impl VersionSortKey {
+    pub fn new(name: &str) -> Self {
+        let (special, name) = match name {
+            "" => (0, name),
+            "." => (1, name),
+            ".." => (2, name),
+            _ if name.starts_with('.') => (3, &name[1..]),
+            _ => (4, name),
+        };
+
+        let stripped_name = remove_file_ending(name);
+        let full = version_components(name);
+        let stripped = if stripped_name == name {
+            full.clone()
+        } else {
+            version_components(stripped_name)
+        };
+
+        Self {
+            special,
+            stripped,
+            full,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::version_cmp::version_cmp;
@@ -357,4 +451,33 @@ mod tests {
             "NULL bytes are handled comparison"
         );
     }
+
+    #[test]
+    fn test_version_sort_key() {
+        use crate::version_cmp::VersionSortKey;
+
+        let mut names = vec![
+            "", ".", "..", ".hidden", ".config", "~", "a~", "a", "ab", "a1", "a01", "a001b2",
+            "aa10bb", "aa11aa", "aa2", "aa100", "file-1.4", "file-1.13", "file12-suffix",
+            "file.txt", "file.txt~", "Apple", "apple", "  a", "100", "20",
+            "aa2000000000000000000000bb", "a..a", "a.+", "a.", "a+",
+        ];
+
+        // Sorting by pre-computed keys must order the names exactly like
+        // sorting with the comparison function.
+        let mut by_key = names.clone();
+        by_key.sort_by_cached_key(|name| VersionSortKey::new(name));
+        names.sort_by(|a, b| version_cmp(a, b));
+        assert_eq!(by_key, names);
+
+        for a in &names {
+            for b in &names {
+                let key_cmp = VersionSortKey::new(a).cmp(&VersionSortKey::new(b));
+                let cmp = version_cmp(a, b);
+                if cmp != Ordering::Equal {
+                    assert_eq!(key_cmp, cmp, "key order differs for {a:?} and {b:?}");
+                }
+            }
+        }
+    }
 }
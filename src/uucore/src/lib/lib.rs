@@ -50,6 +50,8 @@ pub use crate::features::colors;
 pub use crate::features::custom_tz_fmt;
 #[cfg(feature = "encoding")]
 pub use crate::features::encoding;
+#[cfg(feature = "fast-inc")]
+pub use crate::features::fast_inc;
 #[cfg(feature = "format")]
 pub use crate::features::format;
 #[cfg(feature = "fs")]
@@ -80,8 +82,12 @@ pub use crate::features::entries;
 pub use crate::features::perms;
 #[cfg(all(unix, any(feature = "pipes", feature = "buf-copy")))]
 pub use crate::features::pipes;
+#[cfg(all(target_os = "linux", feature = "proc"))]
+pub use crate::features::proc;
 #[cfg(all(unix, feature = "process"))]
 pub use crate::features::process;
+#[cfg(feature = "progress")]
+pub use crate::features::progress;
 #[cfg(all(unix, not(target_os = "fuchsia"), feature = "signals"))]
 pub use crate::features::signals;
 #[cfg(all(
@@ -157,8 +163,12 @@ macro_rules! bin {
             // execute utility code
             let code = $util::uumain(uucore::args_os());
             // (defensively) flush stdout for utility prior to exit; see <https://github.com/rust-lang/rust/issues/23818>
+            // A broken pipe just means the consumer stopped reading and is not
+            // worth reporting, consistent with mute_sigpipe_panic() above.
             if let Err(e) = std::io::stdout().flush() {
-                eprintln!("Error flushing stdout: {}", e);
+                if e.kind() != std::io::ErrorKind::BrokenPipe {
+                    eprintln!("Error flushing stdout: {}", e);
+                }
             }
 
             std::process::exit(code);
@@ -35,6 +35,75 @@ use std::os::wasi::ffi::OsStrExt;
 // These used to be defined here, but they live in their own crate now.
 pub use os_display::{Quotable, Quoted};
 
+/// Display a path quoted according to an explicitly chosen
+/// [`QuotingStyle`](crate::quoting_style::QuotingStyle).
+///
+/// [`Quotable::quote`] always quotes shell-style. Utilities that let the
+/// user choose a quoting style can wrap paths in this instead of picking
+/// an escaping function at every call site.
+///
+/// # Examples
+/// ```rust
+/// use std::path::Path;
+/// use uucore::display::PathQuoter;
+/// use uucore::quoting_style::{Quotes, QuotingStyle};
+///
+/// let style = QuotingStyle::C {
+///     quotes: Quotes::Double,
+/// };
+/// let quoter = PathQuoter::new(Path::new("foo\nbar"), &style);
+/// assert_eq!(quoter.to_string(), "\"foo\\nbar\"");
+/// ```
+#[cfg(feature = "quoting-style")]
+pub struct PathQuoter<'a> {
+    path: &'a std::path::Path,
+    style: &'a crate::quoting_style::QuotingStyle,
+}
+
+#[cfg(feature = "quoting-style")]
+impl<'a> PathQuoter<'a> {
+    pub fn new(path: &'a std::path::Path, style: &'a crate::quoting_style::QuotingStyle) -> Self {
+        Self { path, style }
+    }
+}
+
+#[cfg(feature = "quoting-style")]
+impl std::fmt::Display for PathQuoter<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            crate::quoting_style::escape_name(self.path.as_os_str(), self.style).to_string_lossy()
+        )
+    }
+}
+
+/// The quoting style for paths in diagnostics: always quoted, with
+/// shell escapes for unprintable characters, as GNU quotes file names
+/// in error messages.
+#[cfg(feature = "quoting-style")]
+static DIAGNOSTIC_QUOTING: crate::quoting_style::QuotingStyle =
+    crate::quoting_style::QuotingStyle::Shell {
+        escape: true,
+        always_quote: true,
+        show_control: false,
+    };
+
+/// Quote a path for a diagnostic, without the call site having to pick
+/// a quoting style.
+///
+/// # Examples
+/// ```rust
+/// use std::path::Path;
+/// use uucore::display::diag_quoted;
+///
+/// assert_eq!(diag_quoted(Path::new("foo bar")).to_string(), "'foo bar'");
+/// ```
+#[cfg(feature = "quoting-style")]
+pub fn diag_quoted<P: AsRef<std::path::Path> + ?Sized>(path: &P) -> PathQuoter<'_> {
+    PathQuoter::new(path.as_ref(), &DIAGNOSTIC_QUOTING)
+}
+
 /// Print a path (or `OsStr`-like object) directly to stdout, with a trailing newline,
 /// without losing any information if its encoding is invalid.
 ///
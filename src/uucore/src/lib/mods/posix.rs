@@ -19,6 +19,9 @@
 //! `uniq`
 //!
 use std::env;
+use std::error::Error;
+use std::ffi::OsString;
+use std::fmt;
 
 /// '199209' for POSIX 1003.2-1992, which would define Obsolete mode
 pub const OBSOLETE: usize = 199_209;
@@ -36,6 +39,64 @@ pub fn posix_version() -> Option<usize> {
         .and_then(|v| v.parse::<usize>().ok())
 }
 
+/// The minimum value POSIX guarantees for `ARG_MAX` (`_POSIX_ARG_MAX`),
+/// used as a conservative fallback when the actual limit cannot be
+/// determined.
+pub const POSIX_ARG_MAX: usize = 4096;
+
+/// Returns the maximum combined length of the arguments (and their
+/// terminating NUL bytes) that can be passed to the exec functions.
+///
+/// This queries `sysconf(_SC_ARG_MAX)` on Unix and falls back to
+/// [`POSIX_ARG_MAX`] when the limit is unavailable (and on Windows).
+pub fn arg_max() -> usize {
+    #[cfg(unix)]
+    if let Ok(Some(len)) = nix::unistd::sysconf(nix::unistd::SysconfVar::ARG_MAX) {
+        if let Ok(len) = usize::try_from(len) {
+            return len;
+        }
+    }
+    POSIX_ARG_MAX
+}
+
+/// The error returned by [`check_arg_list_length`] when an argument
+/// list would exceed [`arg_max`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct ArgTooLongError {
+    /// Total size of the argument list, in bytes.
+    pub size: usize,
+    /// The limit that was exceeded.
+    pub limit: usize,
+}
+
+impl fmt::Display for ArgTooLongError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "argument list too long: {} bytes exceeds the limit of {} bytes",
+            self.size, self.limit
+        )
+    }
+}
+
+impl Error for ArgTooLongError {}
+
+/// Check whether `args` fits into the system's argument list size limit.
+///
+/// The size is computed the way the kernel accounts it: the byte length
+/// of every argument plus its terminating NUL byte. Utilities that exec
+/// subprocesses can call this before the exec to produce a useful error
+/// instead of a cryptic `E2BIG` from the kernel.
+pub fn check_arg_list_length(args: &[OsString]) -> Result<(), ArgTooLongError> {
+    let limit = arg_max();
+    let size = args.iter().map(|arg| arg.len() + 1).sum();
+    if size > limit {
+        Err(ArgTooLongError { size, limit })
+    } else {
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::posix::*;
@@ -52,4 +113,29 @@ mod tests {
         env::set_var("_POSIX2_VERSION", MODERN.to_string());
         assert_eq!(posix_version(), Some(MODERN));
     }
+
+    #[test]
+    fn test_arg_max() {
+        // The real limit is system-dependent, but must be at least the
+        // POSIX minimum.
+        assert!(arg_max() >= POSIX_ARG_MAX);
+    }
+
+    #[test]
+    fn test_check_arg_list_length() {
+        let args = vec![OsString::from("echo"), OsString::from("hello")];
+        assert_eq!(check_arg_list_length(&args), Ok(()));
+
+        // A single argument as large as the limit itself cannot fit,
+        // since its terminating NUL byte is accounted for as well.
+        let limit = arg_max();
+        let args = vec![OsString::from("x".repeat(limit))];
+        assert_eq!(
+            check_arg_list_length(&args),
+            Err(ArgTooLongError {
+                size: limit + 1,
+                limit
+            })
+        );
+    }
 }
@@ -100,6 +100,32 @@ pub fn parse_section(section: &str, content: &str) -> Option<String> {
     )
 }
 
+/// Parses the code examples from the `Examples` section, if any.
+///
+/// Each fenced code block in the section is returned as one example,
+/// with the code fences removed. Text between the code blocks is
+/// ignored. An empty vector is returned if there is no `Examples`
+/// section or it contains no code blocks.
+pub fn parse_examples(content: &str) -> Vec<String> {
+    let Some(section) = parse_section("examples", content) else {
+        return Vec::new();
+    };
+
+    let mut examples = Vec::new();
+    let mut current: Option<Vec<&str>> = None;
+    for line in section.lines() {
+        if line.starts_with(MARKDOWN_CODE_FENCES) {
+            match current.take() {
+                Some(lines) => examples.push(lines.join("\n")),
+                None => current = Some(Vec::new()),
+            }
+        } else if let Some(lines) = &mut current {
+            lines.push(line);
+        }
+    }
+    examples
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -233,4 +259,48 @@ mod tests {
 
         assert_eq!(parse_about(input), "about a\n\nabout b");
     }
+
+    #[test]
+    fn test_parse_examples() {
+        let input = "\
+            # ls\n\
+            ```\n\
+            ls [OPTION]... [FILE]...\n\
+            ```\n\
+            \n\
+            About text\n\
+            \n\
+            ## Examples\n\
+            List the current directory:\n\
+            \n\
+            ```shell\n\
+            ls -l\n\
+            ```\n\
+            \n\
+            List another directory:\n\
+            \n\
+            ```\n\
+            ls -l /tmp\n\
+            ls -a /tmp\n\
+            ```\n\
+            \n\
+            ## some section\n\
+            This is some section\n";
+
+        assert_eq!(parse_examples(input), ["ls -l", "ls -l /tmp\nls -a /tmp"]);
+    }
+
+    #[test]
+    fn test_parse_examples_missing_section() {
+        let input = "\
+            # ls\n\
+            ```\n\
+            ls [OPTION]... [FILE]...\n\
+            ```\n\
+            \n\
+            ## some section\n\
+            This is some section\n";
+
+        assert!(parse_examples(input).is_empty());
+    }
 }
@@ -0,0 +1,55 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::io::{BufRead, Cursor};
+use uucore::lines::ByteLines;
+
+/// Build an input of `count` records of `len` bytes each, separated by
+/// `delimiter`.
+fn input(count: usize, len: usize, delimiter: u8) -> Vec<u8> {
+    let mut data = Vec::with_capacity(count * (len + 1));
+    for i in 0..count {
+        data.resize(data.len() + len, b'a' + (i % 26) as u8);
+        data.push(delimiter);
+    }
+    data
+}
+
+/// Benchmark `ByteLines` against `BufRead::split`, which the utilities
+/// used before switching to the shared iterator, for both newline- and
+/// NUL-delimited records of various lengths.
+fn byte_lines(c: &mut Criterion) {
+    for (delim_name, delimiter) in [("newline", b'\n'), ("zero", 0u8)] {
+        let mut group = c.benchmark_group(format!("byte_lines/{delim_name}"));
+        for len in [10, 100, 1000] {
+            let data = input(10_000, len, delimiter);
+            group.throughput(Throughput::Bytes(data.len() as u64));
+            group.bench_with_input(
+                BenchmarkId::new("byte_lines", len),
+                &data,
+                |b, data| {
+                    b.iter(|| {
+                        ByteLines::with_delimiter(Cursor::new(data), delimiter)
+                            .map(|l| l.unwrap().len())
+                            .sum::<usize>()
+                    });
+                },
+            );
+            group.bench_with_input(BenchmarkId::new("split", len), &data, |b, data| {
+                b.iter(|| {
+                    Cursor::new(data)
+                        .split(delimiter)
+                        .map(|l| l.unwrap().len())
+                        .sum::<usize>()
+                });
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, byte_lines);
+criterion_main!(benches);
@@ -0,0 +1,67 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::ffi::OsStr;
+use uucore::quoting_style::{escape_name, Quotes, QuotingStyle};
+
+/// Benchmark `escape_name` on a set of representative file names:
+/// plain names that need no quoting at all, names with spaces and
+/// shell-special characters, control characters and multi-byte
+/// characters.
+fn escape(c: &mut Criterion) {
+    let styles = [
+        (
+            "literal",
+            QuotingStyle::Literal {
+                show_control: false,
+            },
+        ),
+        (
+            "c",
+            QuotingStyle::C {
+                quotes: Quotes::Double,
+            },
+        ),
+        (
+            "shell",
+            QuotingStyle::Shell {
+                escape: false,
+                always_quote: false,
+                show_control: false,
+            },
+        ),
+        (
+            "shell-escape",
+            QuotingStyle::Shell {
+                escape: true,
+                always_quote: false,
+                show_control: false,
+            },
+        ),
+    ];
+
+    let names = [
+        ("plain", "file_name.txt"),
+        ("space", "file name.txt"),
+        ("special", "file*name?[v2].txt"),
+        ("control", "file\tname\n"),
+        ("multibyte", "\u{1F4C4} na\u{EF}ve.txt"),
+    ];
+
+    for (style_name, style) in &styles {
+        let mut group = c.benchmark_group(format!("escape_name/{style_name}"));
+        for (id, name) in &names {
+            group.throughput(Throughput::Bytes(name.len() as u64));
+            group.bench_with_input(BenchmarkId::from_parameter(id), name, |b, name| {
+                b.iter(|| escape_name(OsStr::new(name), style));
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, escape);
+criterion_main!(benches);
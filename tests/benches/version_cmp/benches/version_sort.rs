@@ -0,0 +1,52 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Compare sorting a large directory listing with the pairwise
+//! `version_cmp` comparison against sorting by pre-computed
+//! `VersionSortKey`s, as `ls --sort=version` does.
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use uucore::version_cmp::{version_cmp, VersionSortKey};
+
+/// Generate `n` file names of the shape that version sort is used for,
+/// e.g. `pkg-1.12.3.tar.gz`, in a deterministic but shuffled order.
+fn file_names(n: usize) -> Vec<String> {
+    (0..n)
+        .map(|i| {
+            // A weak LCG is plenty to avoid pre-sorted input.
+            let x = i.wrapping_mul(6_364_136_223_846_793_005).rotate_left(17);
+            format!("pkg-{}.{}.{}.tar.gz", x % 13, x % 101, x % 1009)
+        })
+        .collect()
+}
+
+fn version_sort(c: &mut Criterion) {
+    let names = file_names(100_000);
+
+    let mut group = c.benchmark_group("version_sort");
+    group.throughput(Throughput::Elements(names.len() as u64));
+    group.sample_size(10);
+
+    group.bench_function("pairwise_cmp", |b| {
+        b.iter(|| {
+            let mut names = names.clone();
+            names.sort_by(|a, b| version_cmp(a, b));
+            names
+        });
+    });
+
+    group.bench_function("cached_key", |b| {
+        b.iter(|| {
+            let mut names = names.clone();
+            names.sort_by_cached_key(|name| VersionSortKey::new(name));
+            names
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, version_sort);
+criterion_main!(benches);
@@ -85,6 +85,31 @@ fn test_suffix_param() {
     }
 }
 
+#[test]
+fn test_multiple_param_with_suffix() {
+    new_ucmd!()
+        .args(&["-a", "-s", ".txt", "file1.txt", "file2.txt", "file3.txt"])
+        .succeeds()
+        .stdout_only("file1\nfile2\nfile3\n");
+}
+
+#[test]
+fn test_two_args_without_multiple_is_suffix() {
+    // Without -a, a second operand is the suffix to strip.
+    new_ucmd!()
+        .args(&["file1.txt", ".txt"])
+        .succeeds()
+        .stdout_only("file1\n");
+}
+
+#[test]
+fn test_one_arg() {
+    new_ucmd!()
+        .arg("/foo/bar/baz.txt")
+        .succeeds()
+        .stdout_only("baz.txt\n");
+}
+
 #[test]
 fn test_zero_param() {
     for zero_param in ["-z", "--zero", "--ze"] {
@@ -486,6 +486,49 @@ fn test_base64_multiple_files() {
         .stdout_is_fixture_bytes("base64/md5_multiple_files.expected");
 }
 
+#[test]
+fn test_format_json() {
+    let (at, mut ucmd) = at_and_ucmd!();
+
+    at.write("fi\"le", "9\n");
+    ucmd.arg("--format=json")
+        .arg("fi\"le")
+        .arg("-")
+        .pipe_in("abc")
+        .succeeds()
+        .no_stderr()
+        .stdout_is(
+            "[{\"file\":\"fi\\\"le\",\"checksum\":\"4114975203\",\"algorithm\":\"crc\",\"size\":2},\
+             {\"file\":\"<stdin>\",\"checksum\":\"1219131554\",\"algorithm\":\"crc\",\"size\":3}]\n",
+        );
+}
+
+#[test]
+fn test_format_json_raw_conflicts() {
+    new_ucmd!()
+        .arg("--format=json")
+        .arg("--raw")
+        .arg("lorem_ipsum.txt")
+        .fails()
+        .no_stdout()
+        .stderr_contains("--format")
+        .stderr_contains("cannot be used with")
+        .stderr_contains("--raw");
+}
+
+#[test]
+fn test_format_json_check_conflicts() {
+    new_ucmd!()
+        .arg("--format=json")
+        .arg("--check")
+        .arg("CHECKSUM")
+        .fails()
+        .no_stdout()
+        .stderr_contains("--format")
+        .stderr_contains("cannot be used with")
+        .stderr_contains("--check");
+}
+
 #[test]
 fn test_fail_on_folder() {
     let (at, mut ucmd) = at_and_ucmd!();
@@ -2267,3 +2310,51 @@ mod format_mix {
             .stderr_contains("cksum: WARNING: 1 line is improperly formatted");
     }
 }
+
+#[test]
+fn test_xxh3() {
+    // Known xxHash3-128 test vectors
+    new_ucmd!()
+        .arg("-a")
+        .arg("xxh3")
+        .pipe_in("")
+        .succeeds()
+        .stdout_is("XXH3 (-) = 99aa06d3014798d86001c324468d497f\n");
+
+    new_ucmd!()
+        .arg("-a")
+        .arg("xxh3")
+        .pipe_in("abc")
+        .succeeds()
+        .stdout_is("XXH3 (-) = 06b05ab6733a618578af5f94892f3950\n");
+}
+
+#[test]
+fn test_warn_missing() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+
+    at.mkdir("d");
+    at.write("d/f", "foo\n");
+    at.write("d/unlisted", "bar\n");
+    let checksums =
+        "SHA256 (d/f) = b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c\n";
+    at.write("d/CHECKSUMS", checksums);
+
+    // without the flag, unlisted files go unnoticed
+    scene
+        .ucmd()
+        .args(&["--check", "d/CHECKSUMS"])
+        .succeeds()
+        .stdout_is("d/f: OK\n")
+        .no_stderr();
+
+    // with --warn-missing, each file the checksum file does not cover is
+    // reported; the checksum file itself is not
+    scene
+        .ucmd()
+        .args(&["--check", "--warn-missing", "d/CHECKSUMS"])
+        .succeeds()
+        .stdout_is("d/f: OK\n")
+        .stderr_is("cksum: warning: d/unlisted: not listed in d/CHECKSUMS\n");
+}
@@ -157,6 +157,33 @@ fn output_delimiter() {
         .stdout_is("a\nwordb\nwordwordz\n");
 }
 
+#[test]
+fn output_delimiter_comma() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    at.write("a", "a\nz\n");
+    at.write("b", "b\nz\n");
+    scene
+        .ucmd()
+        .args(&["--output-delimiter=,", "a", "b"])
+        .succeeds()
+        .stdout_is("a\n,b\n,,z\n");
+}
+
+#[test]
+fn output_delimiter_containing_tab() {
+    // The delimiter is used verbatim, even when it contains a tab.
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    at.write("a", "a\nz\n");
+    at.write("b", "b\nz\n");
+    scene
+        .ucmd()
+        .args(&["--output-delimiter=\t|", "a", "b"])
+        .succeeds()
+        .stdout_is("a\n\t|b\n\t|\t|z\n");
+}
+
 #[test]
 fn output_delimiter_hyphen_one() {
     let scene = TestScenario::new(util_name!());
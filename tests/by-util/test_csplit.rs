@@ -392,6 +392,22 @@ fn test_option_keep() {
     assert_eq!(at.read("xx01"), generate(20, 51));
 }
 
+#[test]
+fn test_option_keep_long_form() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    ucmd.args(&["--keep-files", "numbers50.txt", "/20/", "/nope/"])
+        .fails()
+        .stderr_is("csplit: '/nope/': match not found\n")
+        .stdout_is("48\n93\n");
+
+    let count = glob(&at.plus_as_string("xx*"))
+        .expect("there should be splits created")
+        .count();
+    assert_eq!(count, 2);
+    assert_eq!(at.read("xx00"), generate(1, 20));
+    assert_eq!(at.read("xx01"), generate(20, 51));
+}
+
 #[test]
 fn test_option_quiet() {
     for arg in ["-q", "--quiet", "-s", "--silent"] {
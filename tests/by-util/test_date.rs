@@ -14,7 +14,7 @@ fn test_invalid_arg() {
 
 #[test]
 fn test_date_email() {
-    for param in ["--rfc-email", "--rfc-e", "-R"] {
+    for param in ["--rfc-email", "--rfc-e", "-R", "--rfc-2822"] {
         new_ucmd!().arg(param).succeeds();
     }
 }
@@ -411,6 +411,33 @@ fn test_date_string_human() {
     }
 }
 
+#[test]
+fn test_date_string_fractional_timestamp() {
+    new_ucmd!()
+        .env("TZ", "UTC0")
+        .arg("-d")
+        .arg("@1628124123.456789")
+        .arg("+%s.%N")
+        .succeeds()
+        .stdout_only("1628124123.456789000\n");
+
+    // a negative fraction counts backwards from the epoch
+    new_ucmd!()
+        .env("TZ", "UTC0")
+        .arg("-d")
+        .arg("@-1.5")
+        .arg("+%s.%N")
+        .succeeds()
+        .stdout_only("-2.500000000\n");
+
+    new_ucmd!()
+        .arg("-d")
+        .arg("@123.abc")
+        .fails()
+        .no_stdout()
+        .stderr_contains("invalid date");
+}
+
 #[test]
 fn test_invalid_date_string() {
     new_ucmd!()
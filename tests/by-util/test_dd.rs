@@ -334,6 +334,31 @@ fn test_noatime_does_not_update_ofile_atime() {
     assert_eq!(pre_atime, post_atime);
 }
 
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn test_oflag_dsync_writes_ofile() {
+    // dsync opens the output with O_DSYNC; the written data must be
+    // complete and correct despite the synchronous writes.
+    let (fix, mut ucmd) = at_and_ucmd!();
+    ucmd.args(&["status=none", "bs=4", "oflag=dsync", of!("dsync-out.txt")])
+        .pipe_in("0123456789")
+        .succeeds()
+        .no_stderr();
+    assert_eq!(fix.read("dsync-out.txt"), "0123456789");
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn test_oflag_sync_writes_ofile() {
+    // sync opens the output with O_SYNC, which also syncs metadata.
+    let (fix, mut ucmd) = at_and_ucmd!();
+    ucmd.args(&["status=none", "bs=4", "oflag=sync", of!("sync-out.txt")])
+        .pipe_in("0123456789")
+        .succeeds()
+        .no_stderr();
+    assert_eq!(fix.read("sync-out.txt"), "0123456789");
+}
+
 #[cfg(any(target_os = "linux", target_os = "android"))]
 #[test]
 fn test_nocreat_causes_failure_when_outfile_not_present() {
@@ -737,6 +762,30 @@ fn test_partial_records_out() {
         .stderr_is("1+1 records in\n1+1 records out\n");
 }
 
+#[test]
+fn test_ibs_not_dividing_obs() {
+    // Input blocks must be re-buffered into exactly two full 5-byte
+    // output blocks, even though 3 does not divide 5.
+    new_ucmd!()
+        .args(&["ibs=3", "obs=5", "status=noxfer"])
+        .pipe_in("0123456789")
+        .succeeds()
+        .stdout_is("0123456789")
+        .stderr_is("3+1 records in\n2+0 records out\n");
+}
+
+#[test]
+fn test_obs_not_dividing_ibs_flushes_partial_block() {
+    // 10 bytes make three full 3-byte output blocks; the remaining byte
+    // must be flushed as a partial block at end of input.
+    new_ucmd!()
+        .args(&["ibs=5", "obs=3", "status=noxfer"])
+        .pipe_in("0123456789")
+        .succeeds()
+        .stdout_is("0123456789")
+        .stderr_is("2+0 records in\n3+1 records out\n");
+}
+
 #[test]
 fn test_block_cbs16() {
     new_ucmd!()
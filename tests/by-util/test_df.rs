@@ -49,6 +49,16 @@ fn test_df_compatible_sync() {
     new_ucmd!().arg("--sync").succeeds();
 }
 
+#[cfg(target_os = "linux")]
+#[test]
+fn test_df_named_zero_block_filesystem() {
+    // /proc has zero blocks, but naming a file on it must report the proc
+    // filesystem itself, not the filesystem holding the mount point.
+    let output = new_ucmd!().arg("/proc").succeeds().stdout_move_str();
+    let last_line = output.lines().last().unwrap();
+    assert!(last_line.ends_with(" /proc"), "unexpected: {last_line}");
+}
+
 #[test]
 fn test_df_arguments_override_themselves() {
     new_ucmd!().args(&["--help", "--help"]).succeeds();
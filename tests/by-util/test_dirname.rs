@@ -50,6 +50,34 @@ fn test_repeated_zero() {
         .stdout_only("foo\u{0}");
 }
 
+#[test]
+fn test_multiple_paths() {
+    new_ucmd!()
+        .args(&["alpha/beta", "gamma/delta"])
+        .succeeds()
+        .stdout_is("alpha\ngamma\n");
+
+    new_ucmd!()
+        .args(&["-z", "alpha/beta", "gamma/delta"])
+        .succeeds()
+        .stdout_is("alpha\u{0}gamma\u{0}");
+}
+
+#[test]
+fn test_newline_in_path_and_zero() {
+    // With a newline inside the directory component, only the NUL
+    // terminator keeps the output unambiguous.
+    new_ucmd!()
+        .args(&["-z", "al\npha/beta", "gamma/delta"])
+        .succeeds()
+        .stdout_is("al\npha\u{0}gamma\u{0}");
+
+    new_ucmd!()
+        .args(&["al\npha/beta"])
+        .succeeds()
+        .stdout_is("al\npha\n");
+}
+
 #[test]
 fn test_root() {
     new_ucmd!().arg("/").run().stdout_is("/\n");
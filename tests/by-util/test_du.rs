@@ -591,6 +591,76 @@ fn test_du_h_precision() {
     }
 }
 
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn test_du_sparse_file_uses_allocated_blocks() {
+    let ts = TestScenario::new(util_name!());
+    let at = &ts.fixtures;
+
+    std::fs::File::create(at.plus("sparse.bin"))
+        .expect("cannot create test file")
+        .set_len(4 * 1024 * 1024)
+        .expect("cannot truncate test len to size");
+
+    // The default size is based on st_blocks, and a hole allocates none, so
+    // the reported size must be far below the logical size.
+    let result = ts.ucmd().arg("--block-size=1").arg("sparse.bin").succeeds();
+    let allocated: u64 = result
+        .stdout_str()
+        .split('\t')
+        .next()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(allocated < 4 * 1024 * 1024);
+
+    ts.ucmd()
+        .arg("--apparent-size")
+        .arg("--block-size=1")
+        .arg("sparse.bin")
+        .succeeds()
+        .stdout_only("4194304\tsparse.bin\n");
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn test_du_preallocated_file_uses_allocated_blocks() {
+    use std::os::fd::AsRawFd;
+
+    let ts = TestScenario::new(util_name!());
+    let at = &ts.fixtures;
+
+    let file = std::fs::File::create(at.plus("preallocated.bin")).expect("cannot create test file");
+    // Allocate blocks past the end of the file without changing st_size.
+    if unsafe { libc::fallocate(file.as_raw_fd(), libc::FALLOC_FL_KEEP_SIZE, 0, 64 * 1024) } != 0 {
+        print!("Test skipped; filesystem does not support fallocate");
+        return;
+    }
+
+    // st_blocks exceeds st_size / 512 here; the default size must follow
+    // st_blocks while --apparent-size must follow st_size.
+    let result = ts
+        .ucmd()
+        .arg("--block-size=1")
+        .arg("preallocated.bin")
+        .succeeds();
+    let allocated: u64 = result
+        .stdout_str()
+        .split('\t')
+        .next()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(allocated >= 64 * 1024);
+
+    ts.ucmd()
+        .arg("--apparent-size")
+        .arg("--block-size=1")
+        .arg("preallocated.bin")
+        .succeeds()
+        .stdout_only("0\tpreallocated.bin\n");
+}
+
 #[cfg(feature = "touch")]
 #[test]
 fn test_du_time() {
@@ -312,3 +312,11 @@ fn prefix_equal_skip_prefix_equal_two() {
             .stdout_is_fixture("prefixed-one-word-per-line_p=_P=2.txt");
     }
 }
+
+#[test]
+fn test_paragraph_breaks_are_preserved() {
+    new_ucmd!()
+        .pipe_in("aaa bbb\nccc\n\nddd eee\nfff\n")
+        .succeeds()
+        .stdout_is("aaa bbb ccc\n\nddd eee fff\n");
+}
@@ -543,6 +543,27 @@ fn test_bytewise_carriage_return_is_not_word_boundary() {
         .succeeds()
         .stdout_is("fizz\rb\nuzz\rfi\nzzbuzz"); // spell-checker:disable-line
 }
+#[test]
+fn test_bytewise_multibyte_straddling_limit() {
+    // Each of the five characters is three bytes; a limit of 7 must fold
+    // after the last complete character, not inside a UTF-8 sequence.
+    new_ucmd!()
+        .args(&["-w7", "-b"])
+        .pipe_in("ありがとう\n")
+        .succeeds()
+        .stdout_is("あり\nがと\nう\n");
+}
+
+#[test]
+fn test_bytewise_multibyte_wider_than_limit() {
+    // A character wider than the byte limit is emitted whole.
+    new_ucmd!()
+        .args(&["-w2", "-b"])
+        .pipe_in("あい\n")
+        .succeeds()
+        .stdout_is("あ\nい\n");
+}
+
 #[test]
 fn test_obsolete_syntax() {
     new_ucmd!()
@@ -61,3 +61,13 @@ fn test_groups_username_multiple() {
         .stderr_is(exp_result.stderr_str())
         .code_is(exp_result.code());
 }
+
+#[test]
+#[cfg(unix)]
+fn test_groups_nonexistent_user() {
+    new_ucmd!()
+        .arg("nonexistent_user_xyz")
+        .fails()
+        .code_is(1)
+        .stderr_only("groups: 'nonexistent_user_xyz': no such user\n");
+}
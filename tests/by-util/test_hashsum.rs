@@ -133,6 +133,90 @@ fn test_check_sha1() {
         .stderr_is("");
 }
 
+#[test]
+fn test_parallel_matches_sequential() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+
+    let files = ["file1", "file2", "file3", "file4"];
+    for (i, file) in files.iter().enumerate() {
+        at.write(file, &format!("content of file {i}\n"));
+    }
+
+    let sequential = scene
+        .ccmd("sha256sum")
+        .args(&files)
+        .succeeds()
+        .stdout_move_str();
+    scene
+        .ccmd("sha256sum")
+        .arg("--parallel=4")
+        .args(&files)
+        .succeeds()
+        .stdout_is(sequential)
+        .stderr_is("");
+}
+
+#[test]
+fn test_parallel_stdin_stays_sequential() {
+    // With "-" among the arguments the sequential path is taken, so the
+    // first "-" drains stdin and the second hashes empty input, instead
+    // of two threads reading the same descriptor concurrently.
+    TestScenario::new(util_name!())
+        .ccmd("sha1sum")
+        .args(&["--parallel=2", "-", "-"])
+        .pipe_in("abc")
+        .succeeds()
+        .stdout_is(
+            "a9993e364706816aba3e25717850c26c9cd0d89d  -\n\
+             da39a3ee5e6b4b0d3255bfef95601890afd80709  -\n",
+        );
+}
+
+#[test]
+fn test_progress_hidden_without_terminal() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+
+    at.write("testf", "foobar\n");
+    scene
+        .ccmd("sha1sum")
+        .arg("--progress")
+        .arg("testf")
+        .succeeds()
+        .stdout_is("988881adc9fc3655077dc2d4d757d480b5ea0e11  testf\n")
+        .stderr_is("");
+}
+
+#[test]
+fn test_progress_parallel_conflicts() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+
+    at.write("testf", "foobar\n");
+    scene
+        .ccmd("sha1sum")
+        .arg("--progress")
+        .arg("--parallel=2")
+        .arg("testf")
+        .fails()
+        .no_stdout()
+        .stderr_contains("--progress")
+        .stderr_contains("cannot be used with")
+        .stderr_contains("--parallel");
+}
+
+#[test]
+fn test_parallel_invalid() {
+    let scene = TestScenario::new(util_name!());
+    scene
+        .ccmd("sha256sum")
+        .arg("--parallel=0")
+        .arg("-")
+        .fails()
+        .stderr_contains("invalid value '0' for '--parallel");
+}
+
 #[test]
 fn test_check_md5_ignore_missing() {
     let scene = TestScenario::new(util_name!());
@@ -1056,3 +1140,22 @@ fn test_check_sha256_binary() {
         .no_stderr()
         .stdout_is("binary.png: OK\n");
 }
+
+#[test]
+fn test_check_sha3_format_without_algorithm_flag() {
+    // The algorithm is deduced from the algo-based checksum file, as with
+    // the output of GNU-style sha3sum tools.
+    let ts = TestScenario::new(util_name!());
+    let at = &ts.fixtures;
+
+    at.write("f", "hello\n");
+    at.write(
+        "check.sha3",
+        "SHA3-256 (f) = b314e28493eae9dab57ac4f0c6d887bddbbeb810e900d818395ace558e96516d\n",
+    );
+
+    ts.ucmd()
+        .args(&["--check", "check.sha3"])
+        .succeeds()
+        .stdout_is("f: OK\n");
+}
@@ -350,6 +350,35 @@ fn test_install_target_new_file_with_group() {
     assert!(at.file_exists(format!("{dir}/{file}")));
 }
 
+#[test]
+fn test_install_target_new_file_with_numeric_group_not_in_database() {
+    use std::os::unix::fs::MetadataExt;
+
+    let (at, mut ucmd) = at_and_ucmd!();
+    let file = "file";
+    let dir = "target_dir";
+    // A gid this large is unlikely to exist in the group database.
+    let gid: u32 = 0x3fff_fffe;
+
+    at.touch(file);
+    at.mkdir(dir);
+    let result = ucmd
+        .arg(file)
+        .arg("--group")
+        .arg(gid.to_string())
+        .arg(format!("{dir}/{file}"))
+        .run();
+
+    if result.stderr_str().contains("Operation not permitted") {
+        // setting an arbitrary gid requires root
+        println!("TEST SKIPPED");
+        return;
+    }
+
+    result.success();
+    assert_eq!(at.metadata(&format!("{dir}/{file}")).gid(), gid);
+}
+
 #[test]
 fn test_install_target_new_file_with_owner() {
     let (at, mut ucmd) = at_and_ucmd!();
@@ -251,6 +251,41 @@ fn default_format() {
         .stdout_only_fixture("default.expected");
 }
 
+#[test]
+fn empty_field_string_with_format() {
+    // -e replaces fields requested by -o that are missing from the
+    // unpairable side.
+    new_ucmd!()
+        .args(&["-a", "1", "-e", "X", "-o", "0,1.2,2.2"])
+        .arg("unpaired_1.txt")
+        .arg("unpaired_2.txt")
+        .succeeds()
+        .stdout_only("1 a x\n2 b X\n3 c z\n");
+
+    new_ucmd!()
+        .args(&["-a", "2", "-e", "X", "-o", "0,1.2,2.2"])
+        .arg("unpaired_1.txt")
+        .arg("unpaired_2.txt")
+        .succeeds()
+        .stdout_only("1 a x\n3 c z\n4 X w\n");
+
+    new_ucmd!()
+        .args(&["-a", "1", "-a", "2", "-e", "X", "-o", "0,1.2,2.2"])
+        .arg("unpaired_1.txt")
+        .arg("unpaired_2.txt")
+        .succeeds()
+        .stdout_only("1 a x\n2 b X\n3 c z\n4 X w\n");
+
+    // Without -a only paired lines are printed, so there is nothing for
+    // -e to replace.
+    new_ucmd!()
+        .args(&["-e", "X", "-o", "0,1.2,2.2"])
+        .arg("unpaired_1.txt")
+        .arg("unpaired_2.txt")
+        .succeeds()
+        .stdout_only("1 a x\n3 c z\n");
+}
+
 #[test]
 fn unpaired_lines_format() {
     new_ucmd!()
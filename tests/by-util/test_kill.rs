@@ -377,3 +377,33 @@ fn test_kill_with_signal_and_table() {
         .arg("-t")
         .fails();
 }
+
+#[test]
+fn test_kill_timeout_sends_sigkill_to_survivor() {
+    let mut target = Target::new();
+    // The EXIT signal leaves the target alive, so the timeout must expire
+    // and SIGKILL must be sent.
+    new_ucmd!()
+        .arg("--timeout=0.1s")
+        .arg("-s")
+        .arg("EXIT")
+        .arg(format!("{}", target.pid()))
+        .succeeds();
+    assert_eq!(target.wait_for_signal(), Some(libc::SIGKILL));
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn test_kill_timeout_process_exits_in_time() {
+    let mut target = Target::new();
+    // The signal may also be passed as the first positional argument.
+    // The target dies from the SIGTERM (and is seen as a zombie until this
+    // test reaps it), so no SIGKILL must be sent even though the timeout is
+    // far larger than the test timeout.
+    new_ucmd!()
+        .arg("--timeout=30s")
+        .arg("TERM")
+        .arg(format!("{}", target.pid()))
+        .succeeds();
+    assert_eq!(target.wait_for_signal(), Some(libc::SIGTERM));
+}
@@ -37,6 +37,35 @@ fn test_link_no_circular() {
     assert!(!at.file_exists(link));
 }
 
+#[test]
+fn test_link_existing_destination() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    let file = "test_link_existing_destination";
+    let link = "test_link_existing_destination_link";
+
+    at.touch(file);
+    at.touch(link);
+
+    ucmd.args(&[file, link])
+        .fails()
+        .stderr_contains("cannot create link");
+    assert!(at.file_exists(file));
+}
+
+#[test]
+fn test_link_directory_destination() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    let file = "test_link_directory_destination";
+    let dir = "test_link_directory_destination_dir";
+
+    at.touch(file);
+    at.mkdir(dir);
+
+    ucmd.args(&[file, dir])
+        .fails()
+        .stderr_contains("cannot create link");
+}
+
 #[test]
 fn test_link_nonexistent_file() {
     let (at, mut ucmd) = at_and_ucmd!();
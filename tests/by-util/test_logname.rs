@@ -28,3 +28,11 @@ fn test_normal() {
     result.success();
     assert!(!result.stdout_str().trim().is_empty());
 }
+
+#[test]
+fn test_ignores_logname_env() {
+    // The login name comes from the system (getlogin_r), not from the
+    // environment, so changing $LOGNAME must not change the output.
+    let result = new_ucmd!().env("LOGNAME", "somebody_else").run();
+    assert_ne!(result.stdout_str().trim_end(), "somebody_else");
+}
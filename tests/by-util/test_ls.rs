@@ -1740,13 +1740,22 @@ fn test_ls_group_directories_first() {
             .collect::<Vec<_>>(),
     );
 
+    // With --sort=none the directories are still grouped first, but kept
+    // in directory order within each group.
     let result = scene
         .ucmd()
         .arg("-1aU")
         .arg("--group-directories-first")
         .run();
-    let result2 = scene.ucmd().arg("-1aU").run();
-    assert_eq!(result.stdout_str(), result2.stdout_str());
+    let lines: Vec<_> = result.stdout_str().lines().collect();
+    let num_dirs = dirnames.len() + dots.len();
+    assert_eq!(lines.len(), num_dirs + filenames.len());
+    for line in &lines[..num_dirs] {
+        assert!(dirnames.contains(line) || dots.contains(line));
+    }
+    for line in &lines[num_dirs..] {
+        assert!(filenames.contains(line));
+    }
 }
 #[test]
 fn test_ls_sort_none() {
@@ -3430,6 +3439,58 @@ fn test_ls_quoting_style_arg_overrides_env_var() {
     }
 }
 
+#[test]
+fn test_ls_quoting_style_locale() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    at.touch("one two");
+
+    // The quotation marks depend on the locale, which is read from
+    // LC_ALL, LC_MESSAGES and LANG (in that order). Unknown locales
+    // fall back to plain double quotes.
+    for (lang, correct) in [
+        ("C", "'one two'"),
+        ("POSIX", "'one two'"),
+        ("en_US.UTF-8", "'one two'"),
+        ("de_DE.UTF-8", "\u{201E}one two\u{201C}"),
+        ("fr_FR.UTF-8", "\u{00AB}one two\u{00BB}"),
+        ("xx_XX.UTF-8", "\"one two\""),
+        ("", "\"one two\""),
+    ] {
+        for arg in ["--quoting-style=locale", "--quoting-style=clocale"] {
+            scene
+                .ucmd()
+                .env("LC_ALL", "")
+                .env("LANG", lang)
+                .arg(arg)
+                .arg("one two")
+                .succeeds()
+                .stdout_only(format!("{correct}\n"));
+        }
+    }
+
+    // LC_ALL takes precedence over LANG.
+    scene
+        .ucmd()
+        .env("LC_ALL", "fr_FR.UTF-8")
+        .env("LANG", "de_DE.UTF-8")
+        .arg("--quoting-style=locale")
+        .arg("one two")
+        .succeeds()
+        .stdout_only("\u{00AB}one two\u{00BB}\n");
+
+    // The quote character of the locale is escaped C-style if it
+    // appears in the name itself.
+    at.touch("one'two");
+    scene
+        .ucmd()
+        .env("LC_ALL", "en_US.UTF-8")
+        .arg("--quoting-style=locale")
+        .arg("one'two")
+        .succeeds()
+        .stdout_only("'one\\'two'\n");
+}
+
 #[test]
 fn test_ls_quoting_and_color() {
     let scene = TestScenario::new(util_name!());
@@ -5639,3 +5700,25 @@ fn test_time_style_timezone_name() {
         .succeeds()
         .stdout_matches(&re_custom_format);
 }
+
+#[cfg(unix)]
+#[test]
+fn test_ls_unknown_gid_shows_numeric_group() {
+    use std::os::unix::fs::chown;
+
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.touch("f");
+
+    // A gid this large is unlikely to exist in the group database.
+    let gid = 0x3fff_fffe;
+    if chown(at.plus("f"), None, Some(gid)).is_err() {
+        // changing the group to an arbitrary gid requires root
+        println!("TEST SKIPPED");
+        return;
+    }
+
+    ucmd.arg("-l")
+        .arg("f")
+        .succeeds()
+        .stdout_contains(gid.to_string());
+}
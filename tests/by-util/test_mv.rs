@@ -1791,3 +1791,23 @@ fn test_mv_error_msg_with_multiple_sources_that_does_not_exist() {
         .stderr_contains("mv: cannot stat 'a': No such file or directory")
         .stderr_contains("mv: cannot stat 'b/': No such file or directory");
 }
+
+#[test]
+fn test_mv_continues_after_failed_move_and_sets_exit_code() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    at.touch("a");
+    at.touch("b");
+    at.mkdir("d");
+    scene
+        .ucmd()
+        .arg("missing")
+        .arg("a")
+        .arg("b")
+        .arg("d")
+        .fails()
+        .code_is(1)
+        .stderr_contains("mv: cannot stat 'missing': No such file or directory");
+    assert!(at.file_exists("d/a"));
+    assert!(at.file_exists("d/b"));
+}
@@ -90,6 +90,28 @@ fn test_no_renumber() {
     }
 }
 
+#[test]
+fn test_no_renumber_multiple_sections() {
+    for arg in ["-p", "--no-renumber"] {
+        new_ucmd!()
+            .arg(arg)
+            .pipe_in("a\n\\:\\:\nb\n\\:\\:\nc\n")
+            .succeeds()
+            .stdout_is("     1\ta\n\n     2\tb\n\n     3\tc\n");
+    }
+}
+
+#[test]
+fn test_no_renumber_with_unnumbered_lines() {
+    // Lines that are not numbered must not advance the counter, and the
+    // counter must still carry over the section break.
+    new_ucmd!()
+        .args(&["-p", "-b", "p^a"])
+        .pipe_in("apple\nbanana\n\\:\\:\navocado\n")
+        .succeeds()
+        .stdout_is("     1\tapple\n       banana\n\n     2\tavocado\n");
+}
+
 #[test]
 fn test_number_format_ln() {
     for arg in ["-nln", "--number-format=ln"] {
@@ -670,6 +670,26 @@ fn test_transform_with_suffix_and_delimiter() {
         .stdout_only("1.0Kb|2000|3000\n");
 }
 
+#[test]
+fn test_suffix_placed_directly_after_unit() {
+    for (to, expected) in [("si", "1.5MB"), ("iec", "1.5MB"), ("iec-i", "1.5MiB")] {
+        new_ucmd!()
+            .args(&[format!("--to={to}"), "--suffix=B".into()])
+            .pipe_in("1500000")
+            .succeeds()
+            .stdout_only(format!("{expected}\n"));
+    }
+}
+
+#[test]
+fn test_suffix_is_kept_when_rounding_to_zero() {
+    new_ucmd!()
+        .args(&["--to=si", "--round=down", "--suffix=B"])
+        .pipe_in("0.4")
+        .succeeds()
+        .stdout_only("0B\n");
+}
+
 #[test]
 fn test_suffix_with_padding() {
     new_ucmd!()
@@ -1079,6 +1099,60 @@ fn test_format_with_invalid_precision() {
     }
 }
 
+#[test]
+fn test_grouping_en_us_locale() {
+    new_ucmd!()
+        .env("LC_ALL", "en_US.UTF-8")
+        .args(&["--grouping", "1234567"])
+        .succeeds()
+        .stdout_is("1,234,567\n");
+}
+
+#[test]
+fn test_grouping_fr_fr_locale() {
+    new_ucmd!()
+        .env("LC_ALL", "fr_FR.UTF-8")
+        .args(&["--grouping", "1234567"])
+        .succeeds()
+        .stdout_is("1 234 567\n");
+}
+
+#[test]
+fn test_grouping_c_locale_has_no_separator() {
+    new_ucmd!()
+        .env("LC_ALL", "C")
+        .args(&["--grouping", "1234567"])
+        .succeeds()
+        .stdout_is("1234567\n");
+}
+
+#[test]
+fn test_grouping_does_not_apply_to_fractional_part() {
+    new_ucmd!()
+        .env("LC_ALL", "en_US.UTF-8")
+        .args(&["--grouping", "1234567.12345"])
+        .succeeds()
+        .stdout_is("1,234,567.12345\n");
+}
+
+#[test]
+fn test_grouping_conflicts_with_format_option() {
+    new_ucmd!()
+        .args(&["--grouping", "--format=%f", "1234"])
+        .fails()
+        .code_is(1)
+        .stderr_contains("--grouping cannot be combined with --format");
+}
+
+#[test]
+fn test_grouping_conflicts_with_to_option() {
+    new_ucmd!()
+        .args(&["--grouping", "--to=si", "1234"])
+        .fails()
+        .code_is(1)
+        .stderr_contains("grouping cannot be combined with --to");
+}
+
 #[test]
 fn test_format_grouping_conflicts_with_to_option() {
     new_ucmd!()
@@ -201,7 +201,7 @@ fn test_f16() {
     let expected_output = unindent(
         "
             0000000     1.000         0        -0       inf
-            0000010      -inf       NaN -6.104e-5
+            0000010      -inf      -nan -6.104e-5
             0000016
             ",
     );
@@ -229,7 +229,7 @@ fn test_f32() {
     let expected_output = unindent(
         "
             0000000     -1.2345679       12345678  -9.8765427e37             -0
-            0000020            NaN          1e-40 -1.1663108e-38
+            0000020            nan          1e-40 -1.1663108e-38
             0000034
             ",
     );
@@ -271,6 +271,30 @@ fn test_f64() {
         .stdout_is(expected_output);
 }
 
+#[test]
+fn test_f64_non_finite() {
+    let input: [u8; 32] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x7f, // 0x7FF0000000000000 inf
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0xff, // 0xFFF0000000000000 -inf
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf8, 0x7f, // 0x7FF8000000000000 nan
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf8, 0xff,
+    ]; // 0xFFF8000000000000 -nan
+    let expected_output = unindent(
+        "
+            0000000                      inf                     -inf
+            0000020                      nan                     -nan
+            0000040
+            ",
+    );
+    new_ucmd!()
+        .arg("--endian=little")
+        .arg("-F")
+        .run_piped_stdin(&input[..])
+        .success()
+        .no_stderr()
+        .stdout_is(expected_output);
+}
+
 #[test]
 fn test_multibyte() {
     new_ucmd!()
@@ -646,6 +670,45 @@ fn test_skip_bytes_hex() {
         ));
 }
 
+#[test]
+fn test_skip_bytes_per_file() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("test1", "HDR1abcdefgh");
+    at.write("test2", "HDR2ijkl");
+    ucmd.arg("-c")
+        .arg("--skip-bytes-per-file=4")
+        .arg("test1")
+        .arg("test2")
+        .succeeds()
+        .no_stderr()
+        .stdout_is(unindent(
+            "
+            0000000   a   b   c   d   e   f   g   h   i   j   k   l
+            0000014
+            ",
+        ));
+}
+
+#[test]
+fn test_skip_bytes_per_file_short_file() {
+    // a file shorter than the skip contributes no bytes
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("test1", "HD");
+    at.write("test2", "HDR2mnop");
+    ucmd.arg("-c")
+        .arg("--skip-bytes-per-file=4")
+        .arg("test1")
+        .arg("test2")
+        .succeeds()
+        .no_stderr()
+        .stdout_is(unindent(
+            "
+            0000000   m   n   o   p
+            0000004
+            ",
+        ));
+}
+
 #[test]
 fn test_skip_bytes_error() {
     let input = "12345";
@@ -133,6 +133,27 @@ const EXAMPLE_DATA: &[TestData] = &[
         ins: &["1 \na \n", "2\t\nb\t\n"],
         out: "1 |2\t\na |b\t\n",
     },
+    // More files than delimiters: the list must wrap around on every line.
+    TestData {
+        name: "delim-cycle",
+        args: &["-d", ":,"],
+        ins: &["1\n2\n", "a\nb\n", "X\nY\n", "P\nQ\n"],
+        out: "1:a,X:P\n2:b,Y:Q\n",
+    },
+    // Fewer files than delimiters: the surplus delimiter is never used.
+    TestData {
+        name: "delim-surplus",
+        args: &["-d", ":,;"],
+        ins: &["1\n2\n", "a\nb\n"],
+        out: "1:a\n2:b\n",
+    },
+    // A \n escape in the delimiter list splits the output line.
+    TestData {
+        name: "delim-newline-escape",
+        args: &["-d", ":\\n"],
+        ins: &["1\n2\n", "a\nb\n", "X\nY\n"],
+        out: "1:a\nX\n2:b\nY\n",
+    },
 ];
 
 #[test]
@@ -164,3 +164,43 @@ fn test_posix_all() {
     // fail on empty path
     new_ucmd!().args(&["-p", "-P", ""]).fails().no_stdout();
 }
+
+#[test]
+fn test_portability() {
+    // --portability is equivalent to -p -P
+    new_ucmd!()
+        .args(&["--portability", "dir/file-name"])
+        .succeeds()
+        .no_stdout();
+
+    // fail on non-portable chars, diagnosing the offending component
+    new_ucmd!()
+        .args(&["--portability", "dir/f:ile"])
+        .fails()
+        .no_stdout()
+        .stderr_contains("nonportable character ':' in file name component 'f:ile'");
+
+    // fail on leading hyphen in a component
+    new_ucmd!()
+        .args(&["--portability", "dir/-file"])
+        .fails()
+        .no_stdout()
+        .stderr_contains("leading hyphen in file name component '-file'");
+
+    // fail when the whole path exceeds _POSIX_PATH_MAX (256)
+    new_ucmd!()
+        .args(&["--portability", "dir/".repeat(65).as_str()])
+        .fails()
+        .no_stdout()
+        .stderr_contains("limit 256 exceeded by length 260");
+
+    // fail when a component exceeds _POSIX_NAME_MAX (14)
+    new_ucmd!()
+        .args(&["--portability", "dir/filename_longer_than_14"])
+        .fails()
+        .no_stdout()
+        .stderr_contains("limit 14 exceeded by length 23 of file name component");
+
+    // fail on empty path
+    new_ucmd!().args(&["--portability", ""]).fails().no_stdout();
+}
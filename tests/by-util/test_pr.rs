@@ -160,6 +160,38 @@ fn test_with_number_option_with_custom_separator_char_and_width() {
         .stdout_is_templated_fixture(expected_test_file_path, &[("{last_modified_time}", &value)]);
 }
 
+#[test]
+fn test_with_number_separator_long_option() {
+    let test_file_path = "test_num_page.log";
+    let expected_test_file_path = "test_num_page_char.log.expected";
+    let mut scenario = new_ucmd!();
+    let value = file_last_modified_time(&scenario, test_file_path);
+    scenario
+        .args(&["-n", "--number-separator=c", test_file_path])
+        .succeeds()
+        .stdout_is_templated_fixture(expected_test_file_path, &[("{last_modified_time}", &value)]);
+}
+
+#[test]
+fn test_with_number_separator_long_option_and_width() {
+    let test_file_path = "test_num_page.log";
+    let expected_test_file_path = "test_num_page_char_one.log.expected";
+    let mut scenario = new_ucmd!();
+    let value = file_last_modified_time(&scenario, test_file_path);
+    scenario
+        .args(&["-n", "1", "--number-separator=c", test_file_path])
+        .succeeds()
+        .stdout_is_templated_fixture(expected_test_file_path, &[("{last_modified_time}", &value)]);
+}
+
+#[test]
+fn test_with_number_separator_multi_byte() {
+    new_ucmd!()
+        .args(&["-n", "--number-separator=ab", "test_num_page.log"])
+        .fails()
+        .stderr_contains("invalid --number-separator argument 'ab': must be a single byte");
+}
+
 #[test]
 fn test_with_valid_page_ranges() {
     let test_file_path = "test_num_page.log";
@@ -479,6 +479,31 @@ fn sub_any_asterisk_both_params() {
         .stdout_only(" 011 0012");
 }
 
+#[test]
+fn sub_any_asterisk_negative_first_param() {
+    new_ucmd!()
+        .args(&["[%*i]", "-5", "11"])
+        .succeeds()
+        .stdout_only("[11   ]");
+}
+
+#[test]
+fn sub_any_asterisk_negative_second_param() {
+    // A negative precision is taken as if the precision were omitted
+    new_ucmd!()
+        .args(&["%.*i", "-3", "11"])
+        .succeeds()
+        .stdout_only("11");
+}
+
+#[test]
+fn sub_str_asterisk_negative_first_param() {
+    new_ucmd!()
+        .args(&["[%*s]", "-5", "abc"])
+        .succeeds()
+        .stdout_only("[abc  ]");
+}
+
 #[test]
 fn sub_any_asterisk_octal_arg() {
     new_ucmd!()
@@ -540,6 +565,23 @@ fn stop_after_additional_escape() {
         .stdout_only("ABC");
 }
 
+#[test]
+fn escaped_string_hex_and_unicode() {
+    new_ucmd!()
+        .args(&["%b", "\\x41\\u0125\\101"])
+        .succeeds()
+        .stdout_only("AĥA");
+}
+
+#[test]
+fn stop_after_escape_in_b_string_argument() {
+    // \c in a %b argument ends the whole output, not just that argument
+    new_ucmd!()
+        .args(&["A%bC%sE", "B\\cX", "D"])
+        .succeeds()
+        .stdout_only("AB");
+}
+
 #[test]
 fn sub_float_leading_zeroes() {
     new_ucmd!()
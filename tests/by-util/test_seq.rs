@@ -218,6 +218,94 @@ fn test_separator_and_terminator() {
         .stdout_is("2\\n3\\n4\\n5\\n6\n");
 }
 
+#[test]
+fn test_multi_character_separator() {
+    new_ucmd!()
+        .args(&["--separator=, ", "2", "6"])
+        .run()
+        .stdout_is("2, 3, 4, 5, 6\n");
+    new_ucmd!()
+        .args(&["--separator= and ", "1", "3"])
+        .run()
+        .stdout_is("1 and 2 and 3\n");
+}
+
+#[test]
+fn test_separator_containing_newline() {
+    new_ucmd!()
+        .args(&["--separator=,\n", "2", "6"])
+        .run()
+        .stdout_is("2,\n3,\n4,\n5,\n6\n");
+}
+
+#[test]
+fn test_empty_separator() {
+    new_ucmd!()
+        .args(&["--separator=", "1", "5"])
+        .run()
+        .stdout_is("12345\n");
+}
+
+#[test]
+fn test_multi_character_separator_with_format() {
+    new_ucmd!()
+        .args(&["--format=%.2f", "--separator=, ", "1", "3"])
+        .run()
+        .stdout_is("1.00, 2.00, 3.00\n");
+}
+
+#[test]
+fn test_char_range() {
+    new_ucmd!()
+        .args(&["--char-range", "a", "e"])
+        .succeeds()
+        .stdout_only("a\nb\nc\nd\ne\n");
+    new_ucmd!()
+        .args(&["--char-range", "0", "9"])
+        .succeeds()
+        .stdout_only("0\n1\n2\n3\n4\n5\n6\n7\n8\n9\n");
+}
+
+#[test]
+fn test_char_range_with_empty_separator() {
+    new_ucmd!()
+        .args(&["-s", "", "--char-range", "a", "z"])
+        .succeeds()
+        .stdout_only("abcdefghijklmnopqrstuvwxyz\n");
+}
+
+#[test]
+fn test_char_range_descending_prints_nothing() {
+    new_ucmd!()
+        .args(&["--char-range", "z", "a"])
+        .succeeds()
+        .no_stdout();
+}
+
+#[test]
+fn test_char_range_rejects_multi_character_argument() {
+    new_ucmd!()
+        .args(&["--char-range", "ab", "z"])
+        .fails()
+        .stderr_contains("invalid character range argument: 'ab'");
+}
+
+#[test]
+fn test_char_range_rejects_non_ascii_argument() {
+    new_ucmd!()
+        .args(&["--char-range", "à", "z"])
+        .fails()
+        .stderr_contains("invalid character range argument:");
+}
+
+#[test]
+fn test_char_range_requires_two_arguments() {
+    new_ucmd!()
+        .args(&["--char-range", "a"])
+        .fails()
+        .stderr_contains("--char-range requires exactly two arguments");
+}
+
 #[test]
 fn test_equalize_widths() {
     let args = ["-w", "--equal-width"];
@@ -637,6 +637,20 @@ fn test_keys_with_options() {
     }
 }
 
+#[test]
+fn test_keys_with_options_do_not_inherit_global_settings() {
+    // A key with its own options ignores the global settings for that
+    // key: the keys sort ascending numerically despite -r.
+    let input = "b 2\na 1\nc 3\n";
+    for param in [&["-r", "-k", "2,2n"][..], &["-k", "2,2n", "-r"][..]] {
+        new_ucmd!()
+            .args(param)
+            .pipe_in(input)
+            .succeeds()
+            .stdout_only("a 1\nb 2\nc 3\n");
+    }
+}
+
 #[test]
 fn test_keys_with_options_blanks_start() {
     let input = "aa   3 cc\ndd  1 ff\ngg         2 cc\n";
@@ -983,6 +997,34 @@ fn test_compress() {
         .stdout_only_fixture("ext_sort.expected");
 }
 
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn test_compress_zstd() {
+    // The tested binary runs with a cleared environment, so sort looks
+    // up the compress program in the libc default path rather than in
+    // the PATH of this test. Probe the same locations.
+    if std::process::Command::new("zstd")
+        .arg("--version")
+        .env("PATH", "/usr/bin:/bin")
+        .output()
+        .is_err()
+    {
+        print!("Test skipped; requires zstd");
+        return;
+    }
+    new_ucmd!()
+        .args(&[
+            "ext_sort.txt",
+            "-n",
+            "--compress-program",
+            "zstd",
+            "-S",
+            "10",
+        ])
+        .succeeds()
+        .stdout_only_fixture("ext_sort.expected");
+}
+
 #[test]
 #[cfg(any(target_os = "linux", target_os = "android"))]
 fn test_compress_merge() {
@@ -1049,6 +1091,18 @@ fn test_merge_batches() {
         .stdout_only_fixture("ext_sort.expected");
 }
 
+#[test]
+fn test_benchmark() {
+    new_ucmd!()
+        .arg("--benchmark")
+        .pipe_in("b\na\n")
+        .succeeds()
+        .stdout_is("a\nb\n")
+        .stderr_contains("[sort] reading input:")
+        .stderr_contains("[sort] sorting chunk:")
+        .stderr_contains("[sort] writing output:");
+}
+
 #[test]
 fn test_batch_size_invalid() {
     TestScenario::new(util_name!())
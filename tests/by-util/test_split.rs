@@ -1215,6 +1215,48 @@ fn test_elide_dev_null_l_chunks() {
     assert!(!at.plus("xac").exists());
 }
 
+#[test]
+fn test_elide_empty_files_lines_exact_multiple() {
+    // Line mode never creates a trailing empty file when the input is an
+    // exact multiple of the split size, with or without -e.
+    for args in [&["-l", "2", "sixlines.txt"][..], &["-e", "-l", "2", "sixlines.txt"][..]] {
+        let (at, mut ucmd) = at_and_ucmd!();
+        at.write("sixlines.txt", "1\n2\n3\n4\n5\n6\n");
+        ucmd.args(args).succeeds().no_stdout().no_stderr();
+        assert_eq!(at.read("xaa"), "1\n2\n");
+        assert_eq!(at.read("xab"), "3\n4\n");
+        assert_eq!(at.read("xac"), "5\n6\n");
+        assert!(!at.plus("xad").exists());
+    }
+}
+
+#[test]
+fn test_elide_empty_files_bytes_exact_multiple() {
+    // Same for byte mode.
+    for args in [&["-b", "2", "sixbytes.txt"][..], &["-e", "-b", "2", "sixbytes.txt"][..]] {
+        let (at, mut ucmd) = at_and_ucmd!();
+        at.write("sixbytes.txt", "abcdef");
+        ucmd.args(args).succeeds().no_stdout().no_stderr();
+        assert_eq!(at.read("xaa"), "ab");
+        assert_eq!(at.read("xab"), "cd");
+        assert_eq!(at.read("xac"), "ef");
+        assert!(!at.plus("xad").exists());
+    }
+}
+
+#[test]
+fn test_elide_empty_files_n_chunks_exact_multiple() {
+    for args in [&["-n", "3", "sixbytes.txt"][..], &["-e", "-n", "3", "sixbytes.txt"][..]] {
+        let (at, mut ucmd) = at_and_ucmd!();
+        at.write("sixbytes.txt", "abcdef");
+        ucmd.args(args).succeeds().no_stdout().no_stderr();
+        assert_eq!(at.read("xaa"), "ab");
+        assert_eq!(at.read("xab"), "cd");
+        assert_eq!(at.read("xac"), "ef");
+        assert!(!at.plus("xad").exists());
+    }
+}
+
 #[test]
 #[cfg(unix)]
 fn test_number_by_bytes_dev_zero() {
@@ -1977,9 +2019,11 @@ fn test_split_separator_same_multiple() {
 #[test]
 fn test_long_lines() {
     let (at, mut ucmd) = at_and_ucmd!();
-    let line1 = format!("{:131070}\n", "");
+    // widths >= 2^16 are not accepted in format strings, so build the
+    // lines with `str::repeat` instead
+    let line1 = " ".repeat(131_070) + "\n";
     let line2 = format!("{:1}\n", "");
-    let line3 = format!("{:131071}\n", "");
+    let line3 = " ".repeat(131_071) + "\n";
     let infile = [line1, line2, line3].concat();
     ucmd.args(&["-C", "131072"])
         .pipe_in(infile)
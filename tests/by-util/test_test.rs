@@ -289,6 +289,27 @@ fn test_negative_int_compare() {
     }
 }
 
+#[test]
+fn test_int_compare_is_always_decimal() {
+    let scenario = TestScenario::new(util_name!());
+
+    // Leading zeros do not make a number octal: `010` is ten.
+    scenario.ucmd().args(&["010", "-eq", "10"]).succeeds();
+    scenario
+        .ucmd()
+        .args(&["010", "-eq", "8"])
+        .run()
+        .code_is(1);
+
+    // Hexadecimal literals are rejected, matching GNU test.
+    scenario
+        .ucmd()
+        .args(&["0x10", "-eq", "16"])
+        .run()
+        .code_is(2)
+        .stderr_is("test: invalid integer '0x10'\n");
+}
+
 #[test]
 fn test_float_inequality_is_error() {
     new_ucmd!()
@@ -536,6 +557,21 @@ fn test_symlink_is_symlink() {
     scenario.ucmd().args(&["-L", "symlink"]).succeeds();
 }
 
+#[test]
+#[cfg(target_os = "linux")]
+fn test_proc_self_is_symlink_to_directory() {
+    let scenario = TestScenario::new(util_name!());
+
+    // `/proc/self` is a symlink to the `/proc/PID` directory: `-d`
+    // follows the symlink while `-L` does not, matching bash.
+    scenario.ucmd().args(&["-d", "/proc/self"]).succeeds();
+    scenario.ucmd().args(&["-L", "/proc/self"]).succeeds();
+
+    // `/proc/self/fd` is a real directory, not a symlink.
+    scenario.ucmd().args(&["-d", "/proc/self/fd"]).succeeds();
+    scenario.ucmd().args(&["!", "-L", "/proc/self/fd"]).succeeds();
+}
+
 #[test]
 fn test_file_is_not_symlink() {
     let scenario = TestScenario::new(util_name!());
@@ -34,6 +34,30 @@ fn test_sort_floating_nodes() {
         .stdout_only("a\nb\nc\nd\n");
 }
 
+#[test]
+fn test_linear_chain() {
+    new_ucmd!()
+        .pipe_in("a b b c c d d e")
+        .succeeds()
+        .stdout_only("a\nb\nc\nd\ne\n");
+}
+
+#[test]
+fn test_diamond() {
+    // The graph looks like:
+    //
+    //   a
+    //  / \
+    // b   c
+    //  \ /
+    //   d
+    //
+    new_ucmd!()
+        .pipe_in("a b a c b d c d")
+        .succeeds()
+        .stdout_only("a\nb\nc\nd\n");
+}
+
 #[test]
 fn test_no_such_file() {
     new_ucmd!()
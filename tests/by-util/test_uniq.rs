@@ -162,6 +162,24 @@ fn test_stdin_all_repeated() {
         .stdout_is_fixture("sorted-all-repeated.expected");
 }
 
+#[test]
+fn test_stdin_all_repeated_short_flag() {
+    new_ucmd!()
+        .arg("-D")
+        .pipe_in_fixture(INPUT)
+        .run()
+        .stdout_is_fixture("sorted-all-repeated.expected");
+}
+
+#[test]
+fn test_all_repeated_long_form_conflicts_with_group() {
+    new_ucmd!()
+        .args(&["--group", "--all-repeated"])
+        .pipe_in("a\na\nb\n")
+        .fails()
+        .stderr_contains("--group is mutually exclusive with -c/-d/-D/-u");
+}
+
 #[test]
 fn test_all_repeated_followed_by_filename() {
     let filename = "test.txt";
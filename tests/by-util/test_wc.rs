@@ -110,6 +110,48 @@ fn test_utf8_chars() {
         .stdout_is("442\n");
 }
 
+#[test]
+fn test_graphemes_combining_characters() {
+    // "e" + COMBINING ACUTE ACCENT: two code points, one grapheme cluster
+    new_ucmd!()
+        .arg("--graphemes")
+        .pipe_in("e\u{301}e\u{301}\n")
+        .run()
+        .stdout_is("3\n");
+}
+
+#[test]
+fn test_graphemes_emoji_zwj_sequence() {
+    // WOMAN + ZERO WIDTH JOINER + MICROSCOPE: three code points render as a
+    // single emoji, so -m and --graphemes disagree
+    new_ucmd!()
+        .arg("-m")
+        .arg("--graphemes")
+        .pipe_in("\u{1F469}\u{200D}\u{1F52C}\n")
+        .run()
+        .stdout_is("      4       2\n");
+}
+
+#[test]
+fn test_bytes_per_line() {
+    // each CJK character is one column wider than it is long in bytes
+    new_ucmd!()
+        .arg("-B")
+        .pipe_in("日本語\nab\n")
+        .run()
+        .stdout_is("9\n");
+}
+
+#[test]
+fn test_bytes_per_line_vs_max_line_length() {
+    new_ucmd!()
+        .arg("-L")
+        .arg("-B")
+        .pipe_in("日本語\nab\n")
+        .run()
+        .stdout_is("      6       9\n");
+}
+
 #[test]
 fn test_utf8_bytes_chars() {
     new_ucmd!()
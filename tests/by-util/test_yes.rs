@@ -59,6 +59,26 @@ fn test_long_output() {
     run(NO_ARGS, "y\n".repeat(512 * 1024).as_bytes());
 }
 
+/// Close the pipe after the first line, as in `yes | head -n 1`, and
+/// verify that nothing (e.g. a panic message or a flush error) is
+/// printed to stderr.
+#[test]
+fn test_no_stderr_on_closed_pipe() {
+    let mut cmd = new_ucmd!();
+    let mut child = cmd.set_stdout(Stdio::piped()).run_no_wait();
+    let buf = child.stdout_exact_bytes(2);
+    child.close_stdout();
+
+    #[allow(deprecated)]
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(buf.as_slice(), b"y\n");
+    assert!(
+        output.stderr.is_empty(),
+        "unexpected stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
 /// Test with an output that seems likely to get mangled in case of incomplete writes.
 #[test]
 fn test_long_odd_output() {